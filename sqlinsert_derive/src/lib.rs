@@ -5,32 +5,215 @@ use crate::proc_macro::TokenStream;
 use quote::quote;
 
 use inflector::cases::pascalcase::to_pascal_case;
-use syn::{parse_macro_input, Attribute, Data, DataStruct, DeriveInput, Fields, Ident};
+use syn::punctuated::Punctuated;
+use syn::{
+    parse_macro_input, Attribute, Data, DataStruct, DeriveInput, Field, Fields, GenericArgument,
+    Ident, Index, Member, PathArguments, Token, Type,
+};
 
-fn get_sql_column_names(field_identifiers: &Vec<&Option<Ident>>) -> String {
-    // Adapt rust naming conventions to match the table columns.
-    // my_struct_field1, (Rust) -> MyStructField (SQL)
-    let field_names: Vec<String> = field_identifiers
+struct Column<'a> {
+    field: &'a Field,
+    member: Member,
+    name: String,
+    skip: bool,
+}
+
+fn get_columns(input: &DeriveInput) -> syn::Result<Vec<Column<'_>>> {
+    let fields = match &input.data {
+        Data::Struct(DataStruct {
+            fields: Fields::Unit,
+            ..
+        })
+        | Data::Enum(_)
+        | Data::Union(_) => {
+            return Err(syn::Error::new_spanned(
+                &input.ident,
+                "expected a struct with named or unnamed fields",
+            ))
+        }
+        Data::Struct(DataStruct { fields, .. }) => fields,
+    };
+
+    fields
         .iter()
-        .map(|n| match n {
-            Some(e) => to_pascal_case(&e.to_string()),
-            _ => "".to_owned(),
+        .enumerate()
+        .map(|(i, field)| {
+            let member = match &field.ident {
+                Some(ident) => Member::Named(ident.clone()),
+                None => Member::Unnamed(Index::from(i)),
+            };
+            let skip = has_attribute(field, "skip");
+            let name = match (get_string_attribute(field, "column")?, &field.ident) {
+                (Some(name), _) => name,
+                // Adapt rust naming conventions to match the table columns.
+                // my_struct_field1, (Rust) -> MyStructField (SQL)
+                (None, Some(ident)) => to_pascal_case(&ident.to_string()),
+                (None, None) if skip => String::new(),
+                (None, None) => {
+                    return Err(syn::Error::new_spanned(
+                        field,
+                        "tuple struct fields require a #[column(\"Name\")] attribute",
+                    ))
+                }
+            };
+
+            Ok(Column {
+                field,
+                member,
+                name,
+                skip,
+            })
         })
+        .collect()
+}
+
+fn get_sql_column_names(columns: &[&Column]) -> String {
+    columns
+        .iter()
+        .map(|c| c.name.as_str())
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+fn get_sql_type(ty: &Type) -> syn::Result<(&'static str, bool)> {
+    // Map the Rust field type to the SQLite storage class.
+    // Option<T> fields are nullable and all others are NOT NULL.
+    let segment = match ty {
+        Type::Path(path) => path.path.segments.last(),
+        _ => None,
+    }
+    .ok_or_else(|| syn::Error::new_spanned(ty, "unsupported field type"))?;
+
+    match segment.ident.to_string().as_str() {
+        "i8" | "i16" | "i32" | "i64" | "u8" | "u16" | "u32" | "bool" => Ok(("INTEGER", false)),
+        "f32" | "f64" => Ok(("REAL", false)),
+        "String" => Ok(("TEXT", false)),
+        "Option" => match &segment.arguments {
+            PathArguments::AngleBracketed(args) => match args.args.first() {
+                Some(GenericArgument::Type(inner)) => Ok((get_sql_type(inner)?.0, true)),
+                _ => Err(syn::Error::new_spanned(ty, "expected a type argument")),
+            },
+            _ => Err(syn::Error::new_spanned(ty, "expected a type argument")),
+        },
+        _ => Err(syn::Error::new_spanned(
+            ty,
+            "unsupported field type, expected an integer, float, bool, String, or Option",
+        )),
+    }
+}
+
+fn has_attribute(field: &Field, name: &str) -> bool {
+    field.attrs.iter().any(|a| a.path.is_ident(name))
+}
+
+fn get_string_attribute(field: &Field, name: &str) -> syn::Result<Option<String>> {
+    // Attributes with a single string argument like #[column("Name")].
+    match field.attrs.iter().find(|a| a.path.is_ident(name)) {
+        Some(attr) => Ok(Some(attr.parse_args::<syn::LitStr>()?.value())),
+        None => Ok(None),
+    }
+}
+
+fn get_create_table_text(table_name: &str, columns: &[&Column]) -> syn::Result<String> {
+    let mut definitions = Vec::new();
+    let mut constraints = Vec::new();
+
+    for column in columns {
+        let (sql_type, is_option) = get_sql_type(&column.field.ty)?;
+
+        if is_option || has_attribute(column.field, "nullable") {
+            definitions.push(format!("\"{}\"\t{}", column.name, sql_type));
+        } else {
+            definitions.push(format!("\"{}\"\t{} NOT NULL", column.name, sql_type));
+        }
+
+        if let Some(table) = get_string_attribute(column.field, "references")? {
+            constraints.push(format!(
+                "FOREIGN KEY(\"{}\") REFERENCES \"{}\"(\"Id\")",
+                column.name, table
+            ));
+        }
+        if has_attribute(column.field, "primary_key") {
+            constraints.push(format!("PRIMARY KEY(\"{}\")", column.name));
+        }
+    }
+
+    definitions.extend(constraints);
+    Ok(format!(
+        "CREATE TABLE \"{}\" (\n\t{}\n)",
+        table_name,
+        definitions.join(",\n\t")
+    ))
+}
+
+fn get_composite_indexes(
+    attrs: &[Attribute],
+    columns: &[&Column],
+) -> syn::Result<Vec<Vec<String>>> {
+    // Composite indexes are listed on the struct like #[index(material_id, param_id)].
+    // Tuple structs use the column names instead like #[index(MaterialId, ParamId)].
+    let mut indexes = Vec::new();
+    for attr in attrs.iter().filter(|a| a.path.is_ident("index")) {
+        let mut names = Vec::new();
+        for ident in attr.parse_args_with(Punctuated::<Ident, Token![,]>::parse_terminated)? {
+            let column = columns
+                .iter()
+                .find(|c| c.field.ident.as_ref() == Some(&ident) || ident == c.name)
+                .ok_or_else(|| {
+                    syn::Error::new_spanned(&ident, "expected a stored field or column name")
+                })?;
+            names.push(column.name.clone());
+        }
+        if names.is_empty() {
+            return Err(syn::Error::new_spanned(attr, "expected at least one field"));
+        }
+        indexes.push(names);
+    }
+    Ok(indexes)
+}
+
+fn get_create_indexes_text(
+    table_name: &str,
+    attrs: &[Attribute],
+    columns: &[&Column],
+) -> syn::Result<String> {
+    let composite_indexes = get_composite_indexes(attrs, columns)?;
+
+    // Index foreign keys by default since they are used for joins.
+    // Skip columns already covered by the first column of a composite index.
+    let mut indexes: Vec<Vec<String>> = columns
+        .iter()
+        .filter(|c| has_attribute(c.field, "index") || has_attribute(c.field, "references"))
+        .map(|c| vec![c.name.clone()])
+        .filter(|names| !composite_indexes.iter().any(|c| c[0] == names[0]))
         .collect();
+    indexes.extend(composite_indexes);
 
-    field_names.join(",")
+    Ok(indexes
+        .iter()
+        .map(|names| {
+            format!(
+                "CREATE INDEX {}_{}_Idx ON {}({});",
+                table_name,
+                names.join("_"),
+                table_name,
+                names.join(",")
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n"))
 }
 
-fn get_table_name(implementing_type: &Ident, attrs: &Vec<Attribute>) -> String {
+fn get_table_name(implementing_type: &Ident, attrs: &[Attribute]) -> syn::Result<String> {
     for attr in attrs {
         if attr.path.is_ident("table") {
-            let lit: syn::LitStr = attr.parse_args().unwrap();
-            return lit.value();
+            let lit: syn::LitStr = attr.parse_args()?;
+            return Ok(lit.value());
         }
     }
 
     // Use the type's name if no name was specified.
-    implementing_type.to_string()
+    Ok(implementing_type.to_string())
 }
 
 fn get_sql_text(table_name: &str, column_names: &str, column_count: usize) -> String {
@@ -42,26 +225,32 @@ fn get_sql_text(table_name: &str, column_names: &str, column_count: usize) -> St
     )
 }
 
-#[proc_macro_derive(SqlInsert, attributes(table))]
+#[proc_macro_derive(
+    SqlInsert,
+    attributes(table, column, skip, primary_key, references, index, nullable)
+)]
 pub fn sql_insert_derive(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
+    impl_sql_insert(&input)
+        .unwrap_or_else(|e| e.to_compile_error())
+        .into()
+}
 
+fn impl_sql_insert(input: &DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
     let implementing_type = &input.ident;
-    let fields = match &input.data {
-        Data::Struct(DataStruct {
-            fields: Fields::Named(fields),
-            ..
-        }) => &fields.named,
-        _ => panic!("expected a struct with named fields"),
-    };
+    let columns = get_columns(input)?;
+    let columns: Vec<&Column> = columns.iter().filter(|c| !c.skip).collect();
+
+    let members = columns.iter().map(|c| &c.member);
+    let column_names = get_sql_column_names(&columns);
+    let table_name = get_table_name(implementing_type, &input.attrs)?;
+    let sql_text = get_sql_text(&table_name, &column_names, columns.len());
 
-    let field_identifiers: Vec<&Option<Ident>> = fields.iter().map(|field| &field.ident).collect();
-    let column_names = get_sql_column_names(&field_identifiers);
-    let table_name = get_table_name(implementing_type, &input.attrs);
-    let sql_text = get_sql_text(&table_name, &column_names, field_identifiers.len());
+    let create_table_text = get_create_table_text(&table_name, &columns)?;
+    let create_indexes_text = get_create_indexes_text(&table_name, &input.attrs, &columns)?;
 
     // Create the trait implementation.
-    let expanded = quote! {
+    Ok(quote! {
         impl crate::records::SqlInsert for #implementing_type {
             fn insert(&self, transaction: &mut Transaction) -> Result<()> {
                 transaction
@@ -70,13 +259,283 @@ pub fn sql_insert_derive(input: TokenStream) -> TokenStream {
                     )?
                     .execute(params![
                         #(
-                            self.#field_identifiers,
+                            self.#members,
                         )*
                     ])?;
                 Ok(())
             }
         }
-    };
 
-    TokenStream::from(expanded)
+        impl crate::records::SqlSchema for #implementing_type {
+            const TABLE_NAME: &'static str = #table_name;
+
+            fn create_table() -> &'static str {
+                #create_table_text
+            }
+
+            fn create_indexes() -> &'static str {
+                #create_indexes_text
+            }
+        }
+    })
+}
+
+fn get_select_text(table_name: &str, column_names: &str) -> String {
+    // Select the columns in field order so values can be read by index.
+    format!("SELECT {} FROM {}", column_names, table_name)
+}
+
+#[proc_macro_derive(SqlSelect, attributes(table, column, skip))]
+pub fn sql_select_derive(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    impl_sql_select(&input)
+        .unwrap_or_else(|e| e.to_compile_error())
+        .into()
+}
+
+fn impl_sql_select(input: &DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let implementing_type = &input.ident;
+    let columns = get_columns(input)?;
+    let selected_columns: Vec<&Column> = columns.iter().filter(|c| !c.skip).collect();
+
+    let column_names = get_sql_column_names(&selected_columns);
+    let selected_names = selected_columns.iter().map(|c| &c.name);
+    let table_name = get_table_name(implementing_type, &input.attrs)?;
+    let select_text = get_select_text(&table_name, &column_names);
+
+    // Skipped fields aren't stored in the table, so use their default value.
+    let mut row_index = 0usize;
+    let field_values = columns.iter().map(|c| {
+        let member = &c.member;
+        if c.skip {
+            quote! { #member: Default::default() }
+        } else {
+            let i = row_index;
+            row_index += 1;
+            quote! { #member: row.get(#i)? }
+        }
+    });
+
+    // Create the trait implementation.
+    // Tuple structs also support the Self { 0: ... } syntax.
+    Ok(quote! {
+        impl crate::records::SqlSelect for #implementing_type {
+            fn from_row(row: &Row) -> Result<Self> {
+                Ok(Self {
+                    #(#field_values,)*
+                })
+            }
+
+            fn select_all(connection: &Connection) -> Result<Vec<Self>> {
+                connection
+                    .prepare_cached(#select_text)?
+                    .query_map([], Self::from_row)?
+                    .collect()
+            }
+
+            fn select_where<T: ToSql>(
+                connection: &Connection,
+                column: &str,
+                value: T,
+            ) -> Result<Vec<Self>> {
+                // Only allow known column names since the name can't be a query parameter.
+                if ![#(#selected_names),*].contains(&column) {
+                    return Err(rusqlite::Error::InvalidColumnName(column.to_string()));
+                }
+
+                connection
+                    .prepare(&format!("{} WHERE {} = ?", #select_text, column))?
+                    .query_map([value], Self::from_row)?
+                    .collect()
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use quote::ToTokens;
+    use syn::parse_quote;
+
+    fn stored_columns<'a>(columns: &'a [Column<'a>]) -> Vec<&'a Column<'a>> {
+        columns.iter().filter(|c| !c.skip).collect()
+    }
+
+    #[test]
+    fn sql_types() {
+        assert_eq!(
+            ("INTEGER", false),
+            get_sql_type(&parse_quote!(i64)).unwrap()
+        );
+        assert_eq!(
+            ("INTEGER", false),
+            get_sql_type(&parse_quote!(bool)).unwrap()
+        );
+        assert_eq!(("REAL", false), get_sql_type(&parse_quote!(f32)).unwrap());
+        assert_eq!(
+            ("TEXT", false),
+            get_sql_type(&parse_quote!(String)).unwrap()
+        );
+        assert_eq!(
+            ("REAL", true),
+            get_sql_type(&parse_quote!(Option<f64>)).unwrap()
+        );
+        assert!(get_sql_type(&parse_quote!(Vec<u8>)).is_err());
+        assert!(get_sql_type(&parse_quote!(&str)).is_err());
+    }
+
+    #[test]
+    fn named_struct_schema() {
+        let input: DeriveInput = parse_quote! {
+            #[table("Param")]
+            #[index(material_id, param_id)]
+            struct ParamRecord {
+                #[primary_key]
+                id: i64,
+                #[references("CustomParam")]
+                param_id: u32,
+                #[references("Material")]
+                material_id: i64,
+                #[column("Text")]
+                value: Option<String>,
+                #[nullable]
+                #[index]
+                x: f64,
+                #[skip]
+                cached: Vec<u8>,
+            }
+        };
+        let columns = get_columns(&input).unwrap();
+        let columns = stored_columns(&columns);
+        let table_name = get_table_name(&input.ident, &input.attrs).unwrap();
+
+        assert_eq!("Param", table_name);
+        assert_eq!(
+            "Id,ParamId,MaterialId,Text,X",
+            get_sql_column_names(&columns)
+        );
+        assert_eq!(
+            "CREATE TABLE \"Param\" (\n\
+             \t\"Id\"\tINTEGER NOT NULL,\n\
+             \t\"ParamId\"\tINTEGER NOT NULL,\n\
+             \t\"MaterialId\"\tINTEGER NOT NULL,\n\
+             \t\"Text\"\tTEXT,\n\
+             \t\"X\"\tREAL,\n\
+             \tPRIMARY KEY(\"Id\"),\n\
+             \tFOREIGN KEY(\"ParamId\") REFERENCES \"CustomParam\"(\"Id\"),\n\
+             \tFOREIGN KEY(\"MaterialId\") REFERENCES \"Material\"(\"Id\")\n\
+             )",
+            get_create_table_text(&table_name, &columns).unwrap()
+        );
+
+        // MaterialId is already covered by the composite index.
+        assert_eq!(
+            "CREATE INDEX Param_ParamId_Idx ON Param(ParamId);\n\
+             CREATE INDEX Param_X_Idx ON Param(X);\n\
+             CREATE INDEX Param_MaterialId_ParamId_Idx ON Param(MaterialId,ParamId);",
+            get_create_indexes_text(&table_name, &input.attrs, &columns).unwrap()
+        );
+    }
+
+    #[test]
+    fn tuple_struct_schema() {
+        let input: DeriveInput = parse_quote! {
+            #[index(Name, Value)]
+            struct Pair(
+                #[column("Name")]
+                #[index]
+                String,
+                #[skip] u8,
+                #[column("Value")] i64,
+            );
+        };
+        let columns = get_columns(&input).unwrap();
+        let columns = stored_columns(&columns);
+        let table_name = get_table_name(&input.ident, &input.attrs).unwrap();
+
+        // Skipped fields still use up a field index.
+        let members: Vec<String> = columns
+            .iter()
+            .map(|c| c.member.to_token_stream().to_string())
+            .collect();
+        assert_eq!(vec!["0", "2"], members);
+
+        assert_eq!("Pair", table_name);
+        assert_eq!(
+            "INSERT INTO Pair(Name,Value) VALUES(?,?)",
+            get_sql_text(&table_name, &get_sql_column_names(&columns), columns.len())
+        );
+        assert_eq!(
+            "SELECT Name,Value FROM Pair",
+            get_select_text(&table_name, &get_sql_column_names(&columns))
+        );
+        assert_eq!(
+            "CREATE TABLE \"Pair\" (\n\t\"Name\"\tTEXT NOT NULL,\n\t\"Value\"\tINTEGER NOT NULL\n)",
+            get_create_table_text(&table_name, &columns).unwrap()
+        );
+        assert_eq!(
+            "CREATE INDEX Pair_Name_Value_Idx ON Pair(Name,Value);",
+            get_create_indexes_text(&table_name, &input.attrs, &columns).unwrap()
+        );
+    }
+
+    #[test]
+    fn no_indexes() {
+        let input: DeriveInput = parse_quote! {
+            struct Record {
+                #[primary_key]
+                id: i64,
+            }
+        };
+        let columns = get_columns(&input).unwrap();
+        let columns = stored_columns(&columns);
+
+        assert_eq!(
+            "",
+            get_create_indexes_text("Record", &input.attrs, &columns).unwrap()
+        );
+    }
+
+    #[test]
+    fn invalid_composite_indexes() {
+        let input: DeriveInput = parse_quote! {
+            #[index(id, missing)]
+            struct Record {
+                id: i64,
+                #[skip]
+                skipped: i64,
+            }
+        };
+        let columns = get_columns(&input).unwrap();
+        let columns = stored_columns(&columns);
+        assert!(get_composite_indexes(&input.attrs, &columns).is_err());
+
+        // Skipped fields aren't stored, so they can't be indexed.
+        let attrs: Vec<Attribute> = vec![parse_quote!(#[index(skipped)])];
+        assert!(get_composite_indexes(&attrs, &columns).is_err());
+
+        let attrs: Vec<Attribute> = vec![parse_quote!(#[index()])];
+        assert!(get_composite_indexes(&attrs, &columns).is_err());
+    }
+
+    #[test]
+    fn invalid_inputs() {
+        let unit: DeriveInput = parse_quote!(
+            struct Unit;
+        );
+        assert!(get_columns(&unit).is_err());
+
+        let enum_input: DeriveInput = parse_quote!(
+            enum Enum {
+                A,
+            }
+        );
+        assert!(get_columns(&enum_input).is_err());
+
+        let tuple: DeriveInput = parse_quote!(
+            struct Tuple(i64);
+        );
+        assert!(get_columns(&tuple).is_err());
+    }
 }
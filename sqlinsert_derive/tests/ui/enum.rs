@@ -0,0 +1,9 @@
+use sqlinsert_derive::{SqlInsert, SqlSelect};
+
+#[derive(SqlInsert, SqlSelect)]
+enum EnumRecord {
+    A,
+    B,
+}
+
+fn main() {}
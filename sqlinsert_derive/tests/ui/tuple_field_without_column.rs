@@ -0,0 +1,6 @@
+use sqlinsert_derive::{SqlInsert, SqlSelect};
+
+#[derive(SqlInsert, SqlSelect)]
+struct TupleRecord(#[column("Id")] i64, String);
+
+fn main() {}
@@ -0,0 +1,6 @@
+use sqlinsert_derive::{SqlInsert, SqlSelect};
+
+#[derive(SqlInsert, SqlSelect)]
+struct UnitRecord;
+
+fn main() {}
@@ -1,594 +1,207 @@
+use binread::BinReaderExt;
 use rayon::prelude::*;
 use rusqlite::Transaction;
-use rusqlite::{params, Connection, Result};
-use std::path::Path;
+use rusqlite::{Connection, Result};
+use std::collections::{BTreeMap, HashMap};
+use std::convert::TryFrom;
+use std::io::Cursor;
+use std::path::{Path, PathBuf};
 use std::time::Instant;
 
-mod records;
+pub mod records;
 use records::*;
 
-const CUSTOM_PARAM_NAMES: [&str; 366] = [
-    "Diffuse",
-    "Specular",
-    "Ambient",
-    "BlendMap",
-    "Transparency",
-    "DiffuseMapLayer1",
-    "CosinePower",
-    "SpecularPower",
-    "Fresnel",
-    "Roughness",
-    "EmissiveScale",
-    "EnableDiffuse",
-    "EnableSpecular",
-    "EnableAmbient",
-    "DiffuseMapLayer2",
-    "EnableTransparency",
-    "EnableOpacity",
-    "EnableCosinePower",
-    "EnableSpecularPower",
-    "EnableFresnel",
-    "EnableRoughness",
-    "EnableEmissiveScale",
-    "WorldMatrix",
-    "ViewMatrix",
-    "ProjectionMatrix",
-    "WorldViewMatrix",
-    "ViewInverseMatrix",
-    "ViewProjectionMatrix",
-    "WorldViewProjectionMatrix",
-    "WorldInverseTransposeMatrix",
-    "DiffuseMap",
-    "SpecularMap",
-    "AmbientMap",
-    "EmissiveMap",
-    "SpecularMapLayer1",
-    "TransparencyMap",
-    "NormalMap",
-    "DiffuseCubeMap",
-    "ReflectionMap",
-    "ReflectionCubeMap",
-    "RefractionMap",
-    "AmbientOcclusionMap",
-    "LightMap",
-    "AnisotropicMap",
-    "RoughnessMap",
-    "ReflectionMask",
-    "OpacityMask",
-    "UseDiffuseMap",
-    "UseSpecularMap",
-    "UseAmbientMap",
-    "UseEmissiveMap",
-    "UseTranslucencyMap",
-    "UseTransparencyMap",
-    "UseNormalMap",
-    "UseDiffuseCubeMap",
-    "UseReflectionMap",
-    "UseReflectionCubeMap",
-    "UseRefractionMap",
-    "UseAmbientOcclusionMap",
-    "UseLightMap",
-    "UseAnisotropicMap",
-    "UseRoughnessMap",
-    "UseReflectionMask",
-    "UseOpacityMask",
-    "DiffuseSampler",
-    "SpecularSampler",
-    "NormalSampler",
-    "ReflectionSampler",
-    "SpecularMapLayer2",
-    "NormalMapLayer1",
-    "NormalMapBc5",
-    "NormalMapLayer2",
-    "RoughnessMapLayer1",
-    "RoughnessMapLayer2",
-    "UseDiffuseUvTransform1",
-    "UseDiffuseUvTransform2",
-    "UseSpecularUvTransform1",
-    "UseSpecularUvTransform2",
-    "UseNormalUvTransform1",
-    "UseNormalUvTransform2",
-    "ShadowDepthBias",
-    "ShadowMap0",
-    "ShadowMap1",
-    "ShadowMap2",
-    "ShadowMap3",
-    "ShadowMap4",
-    "ShadowMap5",
-    "ShadowMap6",
-    "ShadowMap7",
-    "CastShadow",
-    "ReceiveShadow",
-    "ShadowMapSampler",
-    "Texture0",
-    "Texture1",
-    "Texture2",
-    "Texture3",
-    "Texture4",
-    "Texture5",
-    "Texture6",
-    "Texture7",
-    "Texture8",
-    "Texture9",
-    "Texture10",
-    "Texture11",
-    "Texture12",
-    "Texture13",
-    "Texture14",
-    "Texture15",
-    "Sampler0",
-    "Sampler1",
-    "Sampler2",
-    "Sampler3",
-    "Sampler4",
-    "Sampler5",
-    "Sampler6",
-    "Sampler7",
-    "Sampler8",
-    "Sampler9",
-    "Sampler10",
-    "Sampler11",
-    "Sampler12",
-    "Sampler13",
-    "Sampler14",
-    "Sampler15",
-    "CustomBuffer0",
-    "CustomBuffer1",
-    "CustomBuffer2",
-    "CustomBuffer3",
-    "CustomBuffer4",
-    "CustomBuffer5",
-    "CustomBuffer6",
-    "CustomBuffer7",
-    "CustomMatrix0",
-    "CustomMatrix1",
-    "CustomMatrix2",
-    "CustomMatrix3",
-    "CustomMatrix4",
-    "CustomMatrix5",
-    "CustomMatrix6",
-    "CustomMatrix7",
-    "CustomMatrix8",
-    "CustomMatrix9",
-    "CustomMatrix10",
-    "CustomMatrix11",
-    "CustomMatrix12",
-    "CustomMatrix13",
-    "CustomMatrix14",
-    "CustomMatrix15",
-    "CustomMatrix16",
-    "CustomMatrix17",
-    "CustomMatrix18",
-    "CustomMatrix19",
-    "CustomVector0",
-    "CustomVector1",
-    "CustomVector2",
-    "CustomVector3",
-    "CustomVector4",
-    "CustomVector5",
-    "CustomVector6",
-    "CustomVector7",
-    "CustomVector8",
-    "CustomVector9",
-    "CustomVector10",
-    "CustomVector11",
-    "CustomVector12",
-    "CustomVector13",
-    "CustomVector14",
-    "CustomVector15",
-    "CustomVector16",
-    "CustomVector17",
-    "CustomVector18",
-    "CustomVector19",
-    "CustomColor0",
-    "CustomColor1",
-    "CustomColor2",
-    "CustomColor3",
-    "CustomColor4",
-    "CustomColor5",
-    "CustomColor6",
-    "CustomColor7",
-    "CustomColor8",
-    "CustomColor9",
-    "CustomColor10",
-    "CustomColor11",
-    "CustomColor12",
-    "CustomColor13",
-    "CustomColor14",
-    "CustomColor15",
-    "CustomColor16",
-    "CustomColor17",
-    "CustomColor18",
-    "CustomColor19",
-    "CustomFloat0",
-    "CustomFloat1",
-    "CustomFloat2",
-    "CustomFloat3",
-    "CustomFloat4",
-    "CustomFloat5",
-    "CustomFloat6",
-    "CustomFloat7",
-    "CustomFloat8",
-    "CustomFloat9",
-    "CustomFloat10",
-    "CustomFloat11",
-    "CustomFloat12",
-    "CustomFloat13",
-    "CustomFloat14",
-    "CustomFloat15",
-    "CustomFloat16",
-    "CustomFloat17",
-    "CustomFloat18",
-    "CustomFloat19",
-    "CustomInteger0",
-    "CustomInteger1",
-    "CustomInteger2",
-    "CustomInteger3",
-    "CustomInteger4",
-    "CustomInteger5",
-    "CustomInteger6",
-    "CustomInteger7",
-    "CustomInteger8",
-    "CustomInteger9",
-    "CustomInteger10",
-    "CustomInteger11",
-    "CustomInteger12",
-    "CustomInteger13",
-    "CustomInteger14",
-    "CustomInteger15",
-    "CustomInteger16",
-    "CustomInteger17",
-    "CustomInteger18",
-    "CustomInteger19",
-    "CustomBoolean0",
-    "CustomBoolean1",
-    "CustomBoolean2",
-    "CustomBoolean3",
-    "CustomBoolean4",
-    "CustomBoolean5",
-    "CustomBoolean6",
-    "CustomBoolean7",
-    "CustomBoolean8",
-    "CustomBoolean9",
-    "CustomBoolean10",
-    "CustomBoolean11",
-    "CustomBoolean12",
-    "CustomBoolean13",
-    "CustomBoolean14",
-    "CustomBoolean15",
-    "CustomBoolean16",
-    "CustomBoolean17",
-    "CustomBoolean18",
-    "CustomBoolean19",
-    "UvTransform0",
-    "UvTransform1",
-    "UvTransform2",
-    "UvTransform3",
-    "UvTransform4",
-    "UvTransform5",
-    "UvTransform6",
-    "UvTransform7",
-    "UvTransform8",
-    "UvTransform9",
-    "UvTransform10",
-    "UvTransform11",
-    "UvTransform12",
-    "UvTransform13",
-    "UvTransform14",
-    "UvTransform15",
-    "DiffuseUvTransform1",
-    "DiffuseUvTransform2",
-    "SpecularUvTransform1",
-    "SpecularUvTransform2",
-    "NormalUvTransform1",
-    "NormalUvTransform2",
-    "DiffuseUvTransform",
-    "SpecularUvTransform",
-    "NormalUvTransform",
-    "UseDiffuseUvTransform",
-    "UseSpecularUvTransform",
-    "UseNormalUvTransform",
-    "BlendState0",
-    "BlendState1",
-    "BlendState2",
-    "BlendState3",
-    "BlendState4",
-    "BlendState5",
-    "BlendState6",
-    "BlendState7",
-    "BlendState8",
-    "BlendState9",
-    "BlendState10",
-    "RasterizerState0",
-    "RasterizerState1",
-    "RasterizerState2",
-    "RasterizerState3",
-    "RasterizerState4",
-    "RasterizerState5",
-    "RasterizerState6",
-    "RasterizerState7",
-    "RasterizerState8",
-    "RasterizerState9",
-    "RasterizerState10",
-    "ShadowColor",
-    "EmissiveMapLayer1",
-    "EmissiveMapLayer2",
-    "AlphaTestFunc",
-    "AlphaTestRef",
-    "Texture16",
-    "Texture17",
-    "Texture18",
-    "Texture19",
-    "Sampler16",
-    "Sampler17",
-    "Sampler18",
-    "Sampler19",
-    "CustomVector20",
-    "CustomVector21",
-    "CustomVector22",
-    "CustomVector23",
-    "CustomVector24",
-    "CustomVector25",
-    "CustomVector26",
-    "CustomVector27",
-    "CustomVector28",
-    "CustomVector29",
-    "CustomVector30",
-    "CustomVector31",
-    "CustomVector32",
-    "CustomVector33",
-    "CustomVector34",
-    "CustomVector35",
-    "CustomVector36",
-    "CustomVector37",
-    "CustomVector38",
-    "CustomVector39",
-    "CustomVector40",
-    "CustomVector41",
-    "CustomVector42",
-    "CustomVector43",
-    "CustomVector44",
-    "CustomVector45",
-    "CustomVector46",
-    "CustomVector47",
-    "CustomVector48",
-    "CustomVector49",
-    "CustomVector50",
-    "CustomVector51",
-    "CustomVector52",
-    "CustomVector53",
-    "CustomVector54",
-    "CustomVector55",
-    "CustomVector56",
-    "CustomVector57",
-    "CustomVector58",
-    "CustomVector59",
-    "CustomVector60",
-    "CustomVector61",
-    "CustomVector62",
-    "CustomVector63",
-    "UseBaseColorMap",
-    "UseMetallicMap",
-    "BaseColorMap",
-    "BaseColorMapLayer1",
-    "MetallicMap",
-    "MetallicMapLayer1",
-    "DiffuseLightingAoOffset",
+// ParamId doesn't provide a way to list its variants.
+// Check all IDs up to this value to find the IDs with known names.
+const MAX_PARAM_ID: u64 = 0x1000;
+
+// Tables with a ParamId foreign key to CustomParam.
+const PARAM_TABLES: [&str; 13] = [
+    Vector4Record::TABLE_NAME,
+    FloatRecord::TABLE_NAME,
+    BoolRecord::TABLE_NAME,
+    TextureRecord::TABLE_NAME,
+    BlendStateRecord::TABLE_NAME,
+    RasterizerRecord::TABLE_NAME,
+    BlendStateV15Record::TABLE_NAME,
+    RasterizerV15Record::TABLE_NAME,
+    SamplerRecord::TABLE_NAME,
+    UvTransformRecord::TABLE_NAME,
+    Color4fRecord::TABLE_NAME,
+    ParamParseFailureRecord::TABLE_NAME,
+    ShaderProgramMaterialParamRecord::TABLE_NAME,
 ];
 
-const CREATE_XMB_TABLE: &str = r#"CREATE TABLE "Xmb" (
-	"Id"	INTEGER NOT NULL UNIQUE,
-	"FileName"	TEXT NOT NULL,
-	"Directory"	TEXT NOT NULL,
-	PRIMARY KEY("Id")
-)"#;
-
-const CREATE_XMB_ENTRY_TABLE: &str = r#"CREATE TABLE "XmbEntry" (
-    "Id"	INTEGER NOT NULL UNIQUE,
-    "XmbId" INTEGER NOT NULL,
-    "Name"	TEXT NOT NULL,
-    FOREIGN KEY("XmbId") REFERENCES "Xmb"("Id")
-	PRIMARY KEY("Id")
-)"#;
-
-const CREATE_XMB_ATTRIBUTE_TABLE: &str = r#"CREATE TABLE "XmbAttribute" (
-    "Id"	INTEGER NOT NULL UNIQUE,
-    "XmbEntryId" INTEGER NOT NULL,
-	"Name"	TEXT NOT NULL,
-    "Value"	TEXT NOT NULL,
-    FOREIGN KEY("XmbEntryId") REFERENCES "XmbEntry"("Id")
-	PRIMARY KEY("Id")
-)"#;
-
-const CREATE_MODL_TABLE: &str = r#"CREATE TABLE "Modl" (
-	"Id"	INTEGER NOT NULL UNIQUE,
-    "FileName"	TEXT NOT NULL,
-    "ModelFileName" TEXT NOT NULL,
-    "SkeletonFileName" TEXT NOT NULL,
-    "MaterialFileName" TEXT NOT NULL,
-	"Directory"	TEXT NOT NULL,
-	PRIMARY KEY("Id")
-)"#;
-
-const CREATE_MESH_TABLE: &str = r#"CREATE TABLE "Mesh" (
-	"Id"	INTEGER NOT NULL UNIQUE,
-	"FileName"	TEXT NOT NULL,
-	"Directory"	TEXT NOT NULL,
-	PRIMARY KEY("Id")
-)"#;
-
-const CREATE_MESH_OBJECT_TABLE: &str = r#"CREATE TABLE "MeshObject" (
-    "Id"	INTEGER NOT NULL UNIQUE,
-    "MeshId" INTEGER NOT NULL,
-	"Name"	TEXT NOT NULL,
-    "SubIndex"	INTEGER NOT NULL,
-    FOREIGN KEY("MeshId") REFERENCES "Mesh"("Id")
-	PRIMARY KEY("Id")
-)"#;
-
-const CREATE_MESH_ATTRIBUTE_TABLE: &str = r#"CREATE TABLE "MeshAttribute" (
-    "Id"	INTEGER NOT NULL UNIQUE,
-    "MeshObjectId" INTEGER NOT NULL,
-    "Name"	TEXT NOT NULL,
-    FOREIGN KEY("MeshObjectId") REFERENCES "MeshObject"("Id")
-	PRIMARY KEY("Id")
-)"#;
-
-const CREATE_MATL_TABLE: &str = r#"CREATE TABLE "Matl" (
-	"Id"	INTEGER NOT NULL UNIQUE,
-	"FileName"	TEXT NOT NULL,
-	"Directory"	TEXT NOT NULL,
-	PRIMARY KEY("Id")
-)"#;
-
-const CREATE_MATERIAL_TABLE: &str = r#"CREATE TABLE "Material" (
-	"Id"	INTEGER NOT NULL UNIQUE,
-	"MatlId"	INTEGER NOT NULL,
-	"MaterialLabel"	TEXT NOT NULL,
-    "ShaderLabel"	TEXT NOT NULL,
-    FOREIGN KEY("MatlId") REFERENCES "Matl"("Id")
-	PRIMARY KEY("Id")
-)"#;
-
-const CREATE_VECTOR_TABLE: &str = r#"CREATE TABLE "CustomVectorParam" (
-	"Id"	INTEGER NOT NULL UNIQUE,
-	"ParamId"	INTEGER NOT NULL,
-	"MaterialId"	INTEGER NOT NULL,
-	"X"	REAL NOT NULL,
-	"Y"	REAL NOT NULL,
-	"Z"	REAL NOT NULL,
-    "W"	REAL NOT NULL,
-    FOREIGN KEY("MaterialId") REFERENCES "Material"("Id"),
-	FOREIGN KEY("ParamId") REFERENCES "CustomParam"("Id")
-	PRIMARY KEY("Id")
-)"#;
-
-const CREATE_PARAM_TABLE: &str = r#"CREATE TABLE "CustomParam" (
-	"Id"	INTEGER NOT NULL UNIQUE,
-	"Name"	TEXT NOT NULL,
-	PRIMARY KEY("Id")
-)"#;
-
-const CREATE_FLOAT_TABLE: &str = r#"CREATE TABLE "CustomFloatParam" (
-	"Id"	INTEGER NOT NULL UNIQUE,
-	"ParamId"	INTEGER,
-	"MaterialId"	INTEGER NOT NULL,
-    "Value"	INTEGER NOT NULL,
-    FOREIGN KEY("MaterialId") REFERENCES "Material"("Id"),
-	FOREIGN KEY("ParamId") REFERENCES "CustomParam"("Id")
-	PRIMARY KEY("Id")
-)"#;
-
-const CREATE_BOOLEAN_TABLE: &str = r#"CREATE TABLE "CustomBooleanParam" (
-	"Id"	INTEGER NOT NULL UNIQUE,
-	"ParamId"	INTEGER NOT NULL,
-	"MaterialId"	INTEGER NOT NULL,
-    "Value"	INTEGER NOT NULL,
-    PRIMARY KEY("Id"),
-    FOREIGN KEY("MaterialId") REFERENCES "Material"("Id"),
-	FOREIGN KEY("ParamId") REFERENCES "CustomParam"("Id")
-)"#;
-
-const CREATE_TEXTURE_TABLE: &str = r#"CREATE TABLE "Texture" (
-	"Id"	INTEGER NOT NULL UNIQUE,
-	"ParamId"	INTEGER NOT NULL,
-	"MaterialId"	INTEGER NOT NULL,
-    "Value"	TEXT,
-    FOREIGN KEY("MaterialId") REFERENCES "Material"("Id"),
-	FOREIGN KEY("ParamId") REFERENCES "CustomParam"("Id"),
-	PRIMARY KEY("Id")
-)"#;
-
-const CREATE_BLENDSTATE_TABLE: &str = r#"CREATE TABLE "BlendState" (
-	"Id"	INTEGER NOT NULL UNIQUE,
-    "ParamId"	INTEGER NOT NULL,
-	"MaterialId"	INTEGER NOT NULL,
-	"SourceColor"	INTEGER NOT NULL,
-	"Unk2"	INTEGER NOT NULL,
-	"DestinationColor"	INTEGER NOT NULL,
-	"Unk4"	INTEGER NOT NULL,
-	"Unk5"	INTEGER NOT NULL,
-	"Unk6"	INTEGER NOT NULL,
-	"Unk7"	INTEGER NOT NULL,
-	"Unk8"	INTEGER NOT NULL,
-	"Unk9"	INTEGER NOT NULL,
-	"Unk10"	INTEGER NOT NULL,
-    FOREIGN KEY("MaterialId") REFERENCES "Material"("Id"),
-	FOREIGN KEY("ParamId") REFERENCES "CustomParam"("Id"),
-	PRIMARY KEY("Id")
-)"#;
-
-const CREATE_RASTERIZERSTATE_TABLE: &str = r#"CREATE TABLE "RasterizerState" (
-	"Id"	INTEGER NOT NULL UNIQUE,
-    "ParamId"	INTEGER NOT NULL,
-	"MaterialId"	INTEGER NOT NULL,
-	"FillMode"	INTEGER NOT NULL,
-	"CullMode"	INTEGER NOT NULL,
-	"DepthBias"	REAL NOT NULL,
-	"Unk4"	REAL NOT NULL,
-	"Unk5"	REAL NOT NULL,
-	"Unk6"	INTEGER NOT NULL,
-    FOREIGN KEY("MaterialId") REFERENCES "Material"("Id"),
-	FOREIGN KEY("ParamId") REFERENCES "CustomParam"("Id"),
-	PRIMARY KEY("Id")
-)"#;
-
-const CREATE_SAMPLER_TABLE: &str = r#"CREATE TABLE "Sampler" (
-	"Id"	INTEGER NOT NULL UNIQUE,
-	"ParamId"	INTEGER NOT NULL,
-	"MaterialId"	INTEGER NOT NULL,
-	"Wraps"	INTEGER NOT NULL,
-	"Wrapt"	INTEGER NOT NULL,
-	"Wrapr"	INTEGER NOT NULL,
-	"MinFilter"	INTEGER NOT NULL,
-	"MagFilter"	INTEGER NOT NULL,
-	"TextureFilteringType"	INTEGER NOT NULL,
-	"BorderColorR"	Real NOT NULL,
-	"BorderColorG"	Real NOT NULL,
-	"BorderColorB"	Real NOT NULL,
-	"BorderColorA"	Real NOT NULL,
-	"Unk11"	INTEGER NOT NULL,
-	"Unk12"	INTEGER NOT NULL,
-	"LodBias"	REAL NOT NULL,
-    "MaxAnisotropy"	INTEGER NOT NULL,
-    PRIMARY KEY("Id"),
-    FOREIGN KEY("MaterialId") REFERENCES "Material"("Id"),
-	FOREIGN KEY("ParamId") REFERENCES "CustomParam"("Id")
-)"#;
+// Use a macro to apply the same generic function to every record type.
+macro_rules! for_each_record {
+    ($f:ident, $transaction:expr) => {
+        $f::<CustomParamRecord>($transaction)?;
+        $f::<ModlRecord>($transaction)?;
+        $f::<ModlMaterialFileRecord>($transaction)?;
+        $f::<ModlEntryRecord>($transaction)?;
+        $f::<MeshRecord>($transaction)?;
+        $f::<MeshObjectRecord>($transaction)?;
+        $f::<MeshAttributeRecord>($transaction)?;
+        $f::<MeshAttributeNameRecord>($transaction)?;
+        $f::<MeshRiggingGroupRecord>($transaction)?;
+        $f::<VertexAttributeStatsRecord>($transaction)?;
+        $f::<SkelRecord>($transaction)?;
+        $f::<BoneRecord>($transaction)?;
+        $f::<AnimRecord>($transaction)?;
+        $f::<AnimGroupRecord>($transaction)?;
+        $f::<AnimNodeRecord>($transaction)?;
+        $f::<AnimTrackRecord>($transaction)?;
+        $f::<AnimMaterialValueRecord>($transaction)?;
+        $f::<HlpbRecord>($transaction)?;
+        $f::<HlpbAimConstraintRecord>($transaction)?;
+        $f::<HlpbOrientConstraintRecord>($transaction)?;
+        $f::<NufxRecord>($transaction)?;
+        $f::<ShaderProgramRecord>($transaction)?;
+        $f::<ShaderProgramVertexAttributeRecord>($transaction)?;
+        $f::<ShaderProgramMaterialParamRecord>($transaction)?;
+        $f::<AdjRecord>($transaction)?;
+        $f::<AdjEntryRecord>($transaction)?;
+        $f::<NrpdRecord>($transaction)?;
+        $f::<NrpdFrameBufferRecord>($transaction)?;
+        $f::<NrpdStateContainerRecord>($transaction)?;
+        $f::<NrpdSamplerRecord>($transaction)?;
+        $f::<NrpdDepthStateRecord>($transaction)?;
+        $f::<NrpdBlendStateRecord>($transaction)?;
+        $f::<NrpdRasterizerStateRecord>($transaction)?;
+        $f::<ShdrRecord>($transaction)?;
+        $f::<ShaderRecord>($transaction)?;
+        $f::<MatlRecord>($transaction)?;
+        $f::<XmbRecord>($transaction)?;
+        $f::<XmbEntryRecord>($transaction)?;
+        $f::<XmbAttributeRecord>($transaction)?;
+        $f::<MaterialRecord>($transaction)?;
+        $f::<Vector4Record>($transaction)?;
+        $f::<FloatRecord>($transaction)?;
+        $f::<BoolRecord>($transaction)?;
+        $f::<TextureRecord>($transaction)?;
+        $f::<BlendStateRecord>($transaction)?;
+        $f::<RasterizerRecord>($transaction)?;
+        $f::<BlendStateV15Record>($transaction)?;
+        $f::<RasterizerV15Record>($transaction)?;
+        $f::<SamplerRecord>($transaction)?;
+        $f::<UvTransformRecord>($transaction)?;
+        $f::<Color4fRecord>($transaction)?;
+        $f::<ParamParseFailureRecord>($transaction)?;
+    };
+}
+
+fn create_table<T: SqlSchema>(transaction: &Transaction) -> Result<()> {
+    transaction.execute(T::create_table(), [])?;
+    Ok(())
+}
 
 fn create_tables(transaction: &mut Transaction) -> Result<()> {
-    transaction.execute(CREATE_PARAM_TABLE, [])?;
-    transaction.execute(CREATE_MODL_TABLE, [])?;
-    transaction.execute(CREATE_MESH_TABLE, [])?;
-    transaction.execute(CREATE_MESH_OBJECT_TABLE, [])?;
-    transaction.execute(CREATE_MESH_ATTRIBUTE_TABLE, [])?;
-    transaction.execute(CREATE_MATL_TABLE, [])?;
-    transaction.execute(CREATE_XMB_TABLE, [])?;
-    transaction.execute(CREATE_XMB_ENTRY_TABLE, [])?;
-    transaction.execute(CREATE_XMB_ATTRIBUTE_TABLE, [])?;
-    transaction.execute(CREATE_MATERIAL_TABLE, [])?;
-    transaction.execute(CREATE_VECTOR_TABLE, [])?;
-    transaction.execute(CREATE_FLOAT_TABLE, [])?;
-    transaction.execute(CREATE_BOOLEAN_TABLE, [])?;
-    transaction.execute(CREATE_TEXTURE_TABLE, [])?;
-    transaction.execute(CREATE_BLENDSTATE_TABLE, [])?;
-    transaction.execute(CREATE_RASTERIZERSTATE_TABLE, [])?;
-    transaction.execute(CREATE_SAMPLER_TABLE, [])?;
+    for_each_record!(create_table, transaction);
+    Ok(())
+}
+
+/// Find the ID and name for each variant of ParamId.
+fn custom_params() -> Vec<(u64, String)> {
+    (0..MAX_PARAM_ID)
+        .filter_map(|id| {
+            Cursor::new(id.to_le_bytes())
+                .read_le::<ssbh_lib::formats::matl::ParamId>()
+                .ok()
+                .map(|param_id| (id, format!("{:?}", param_id)))
+        })
+        .collect()
+}
+
+fn insert_custom_params(transaction: &mut Transaction) -> Result<()> {
+    for (id, name) in custom_params() {
+        // The type is set after processing the matl files.
+        let record = CustomParamRecord {
+            id: id as i64,
+            name,
+            param_type: None,
+            is_known: true,
+        };
+        record.insert(transaction)?;
+    }
 
     Ok(())
 }
 
-fn insert_custom_params(transaction: &Transaction) -> Result<()> {
-    let mut statement =
-        transaction.prepare_cached("INSERT INTO CustomParam(Id,Name) VALUES(?,?)")?;
+/// Add placeholder CustomParam rows for any referenced param IDs without a known name.
+/// Returns the number of references for each unknown param ID.
+fn insert_unknown_params(transaction: &mut Transaction) -> Result<BTreeMap<i64, i64>> {
+    let mut unknown_params = BTreeMap::new();
+    for table in &PARAM_TABLES {
+        let mut statement = transaction.prepare(&format!(
+            "SELECT ParamId, COUNT(*) FROM {} WHERE ParamId NOT IN (SELECT Id FROM CustomParam) GROUP BY ParamId",
+            table
+        ))?;
+        let rows = statement.query_map([], |row| Ok((row.get(0)?, row.get(1)?)))?;
+        for row in rows {
+            let (param_id, count): (i64, i64) = row?;
+            *unknown_params.entry(param_id).or_insert(0) += count;
+        }
+    }
+
+    for param_id in unknown_params.keys() {
+        let record = CustomParamRecord {
+            id: *param_id,
+            name: format!("Unknown0x{:X}", param_id),
+            param_type: None,
+            is_known: false,
+        };
+        record.insert(transaction)?;
+    }
+
+    Ok(unknown_params)
+}
 
-    for (i, name) in CUSTOM_PARAM_NAMES.iter().enumerate() {
-        statement.execute(params![i as u32, name])?;
+fn print_unknown_params(unknown_params: &BTreeMap<i64, i64>) {
+    if unknown_params.is_empty() {
+        return;
     }
 
+    println!(
+        "Found {} param IDs without a known name:",
+        unknown_params.len()
+    );
+    for (param_id, count) in unknown_params {
+        println!("Unknown0x{:X}: {} references", param_id, count);
+    }
+}
+
+fn set_param_type<T: SqlSchema>(transaction: &Transaction, param_type: &str) -> Result<()> {
+    transaction.execute(
+        &format!(
+            "UPDATE CustomParam SET ParamType = ? WHERE Id IN (SELECT ParamId FROM {})",
+            T::TABLE_NAME
+        ),
+        [param_type],
+    )?;
     Ok(())
 }
 
+fn set_param_types(connection: &mut Connection) -> Result<()> {
+    let transaction = connection.transaction()?;
+
+    // Use the param data type from the matl files since the name doesn't always indicate the type.
+    // Params not used by any matl don't have a type.
+    set_param_type::<FloatRecord>(&transaction, "Float")?;
+    set_param_type::<BoolRecord>(&transaction, "Boolean")?;
+    set_param_type::<Vector4Record>(&transaction, "Vector4")?;
+    // The Unk7 variant holds a Color4f, so use the data type to match the table name.
+    set_param_type::<Color4fRecord>(&transaction, "Color4f")?;
+    set_param_type::<TextureRecord>(&transaction, "MatlString")?;
+    set_param_type::<SamplerRecord>(&transaction, "Sampler")?;
+    set_param_type::<UvTransformRecord>(&transaction, "UvTransform")?;
+    set_param_type::<BlendStateRecord>(&transaction, "BlendState")?;
+    set_param_type::<BlendStateV15Record>(&transaction, "BlendState")?;
+    set_param_type::<RasterizerRecord>(&transaction, "RasterizerState")?;
+    set_param_type::<RasterizerV15Record>(&transaction, "RasterizerState")?;
+
+    transaction.commit()
+}
+
 fn process_matl(
     matl: &ssbh_lib::formats::matl::Matl,
     directory_id: String,
@@ -596,7 +209,8 @@ fn process_matl(
 ) -> Vec<Box<dyn SqlInsert>> {
     let mut records: Vec<Box<dyn SqlInsert>> = Vec::new();
 
-    let (matl_id, matl_record) = MatlRecord::create_record(directory_id, file_name);
+    let version = format!("{}.{}", matl.major_version, matl.minor_version);
+    let (matl_id, matl_record) = MatlRecord::create_record(directory_id, file_name, version);
     records.push(Box::new(matl_record));
 
     for entry in &matl.entries.elements {
@@ -610,62 +224,39 @@ fn process_matl(
         );
         records.push(Box::new(material_record));
 
+        // Both versions store their params in the same tables.
         match &entry.attributes {
-            ssbh_lib::formats::matl::MatlAttributes::Attributes15(_) => (), // Skip versions not used by Smash.
+            ssbh_lib::formats::matl::MatlAttributes::Attributes15(attributes) => {
+                for attribute in &attributes.elements {
+                    let param_id = attribute.param_id as u32;
+
+                    match attribute.param.data.as_ref() {
+                        Some(data) => records.push(process_param_v15(param_id, material_id, data)),
+                        None => records.push(Box::new(
+                            ParamParseFailureRecord::create_record(
+                                param_id,
+                                material_id,
+                                attribute.param.data_type as u32,
+                            )
+                            .1,
+                        )),
+                    }
+                }
+            }
             ssbh_lib::formats::matl::MatlAttributes::Attributes16(attributes) => {
                 for attribute in &attributes.elements {
                     let param_id = attribute.param_id as u32;
 
                     match attribute.param.data.as_ref() {
-                        Some(data) => match data {
-                            ssbh_lib::formats::matl::ParamV16::Boolean(val) => {
-                                records.push(Box::new(
-                                    BoolRecord::create_record(param_id, material_id, *val > 0).1,
-                                ));
-                            }
-                            ssbh_lib::formats::matl::ParamV16::Float(val) => {
-                                records.push(Box::new(
-                                    FloatRecord::create_record(param_id, material_id, *val as f64)
-                                        .1,
-                                ));
-                            }
-                            ssbh_lib::formats::matl::ParamV16::Vector4(val) => {
-                                records.push(Box::new(
-                                    Vector4Record::create_record(
-                                        param_id,
-                                        material_id,
-                                        val.x as f64,
-                                        val.y as f64,
-                                        val.z as f64,
-                                        val.w as f64,
-                                    )
-                                    .1,
-                                ));
-                            }
-                            ssbh_lib::formats::matl::ParamV16::MatlString(val) => {
-                                let text = val.to_string_lossy().to_string();
-                                records.push(Box::new(
-                                    TextureRecord::create_record(param_id, material_id, text).1,
-                                ));
-                            }
-                            ssbh_lib::formats::matl::ParamV16::Sampler(val) => {
-                                records.push(Box::new(
-                                    SamplerRecord::create_record(param_id, material_id, &val).1,
-                                ));
-                            }
-                            ssbh_lib::formats::matl::ParamV16::BlendState(val) => {
-                                records.push(Box::new(
-                                    BlendStateRecord::create_record(param_id, material_id, &val).1,
-                                ));
-                            }
-                            ssbh_lib::formats::matl::ParamV16::RasterizerState(val) => {
-                                records.push(Box::new(
-                                    RasterizerRecord::create_record(param_id, material_id, &val).1,
-                                ));
-                            }
-                            _ => (),
-                        },
-                        None => (),
+                        Some(data) => records.push(process_param_v16(param_id, material_id, data)),
+                        None => records.push(Box::new(
+                            ParamParseFailureRecord::create_record(
+                                param_id,
+                                material_id,
+                                attribute.param.data_type as u32,
+                            )
+                            .1,
+                        )),
                     }
                 }
             }
@@ -675,31 +266,334 @@ fn process_matl(
     records
 }
 
+fn process_param_v16(
+    param_id: u32,
+    material_id: i64,
+    data: &ssbh_lib::formats::matl::ParamV16,
+) -> Box<dyn SqlInsert> {
+    match data {
+        ssbh_lib::formats::matl::ParamV16::Boolean(val) => {
+            Box::new(BoolRecord::create_record(param_id, material_id, *val > 0).1)
+        }
+        ssbh_lib::formats::matl::ParamV16::Float(val) => {
+            Box::new(FloatRecord::create_record(param_id, material_id, *val as f64).1)
+        }
+        ssbh_lib::formats::matl::ParamV16::Vector4(val) => Box::new(
+            Vector4Record::create_record(
+                param_id,
+                material_id,
+                val.x as f64,
+                val.y as f64,
+                val.z as f64,
+                val.w as f64,
+            )
+            .1,
+        ),
+        ssbh_lib::formats::matl::ParamV16::MatlString(val) => {
+            let text = val.to_string_lossy().to_string();
+            Box::new(TextureRecord::create_record(param_id, material_id, text).1)
+        }
+        ssbh_lib::formats::matl::ParamV16::Sampler(val) => {
+            Box::new(SamplerRecord::create_record(param_id, material_id, val).1)
+        }
+        ssbh_lib::formats::matl::ParamV16::UvTransform(val) => {
+            Box::new(UvTransformRecord::create_record(param_id, material_id, val).1)
+        }
+        ssbh_lib::formats::matl::ParamV16::BlendState(val) => {
+            Box::new(BlendStateRecord::create_record(param_id, material_id, val).1)
+        }
+        ssbh_lib::formats::matl::ParamV16::RasterizerState(val) => {
+            Box::new(RasterizerRecord::create_record(param_id, material_id, val).1)
+        }
+        ssbh_lib::formats::matl::ParamV16::Unk7(val) => {
+            Box::new(Color4fRecord::create_record(param_id, material_id, val).1)
+        }
+    }
+}
+
+fn process_param_v15(
+    param_id: u32,
+    material_id: i64,
+    data: &ssbh_lib::formats::matl::ParamV15,
+) -> Box<dyn SqlInsert> {
+    match data {
+        ssbh_lib::formats::matl::ParamV15::Boolean(val) => {
+            Box::new(BoolRecord::create_record(param_id, material_id, *val > 0).1)
+        }
+        ssbh_lib::formats::matl::ParamV15::Float(val) => {
+            Box::new(FloatRecord::create_record(param_id, material_id, *val as f64).1)
+        }
+        ssbh_lib::formats::matl::ParamV15::Vector4(val) => Box::new(
+            Vector4Record::create_record(
+                param_id,
+                material_id,
+                val.x as f64,
+                val.y as f64,
+                val.z as f64,
+                val.w as f64,
+            )
+            .1,
+        ),
+        ssbh_lib::formats::matl::ParamV15::MatlString(val) => {
+            let text = val.to_string_lossy().to_string();
+            Box::new(TextureRecord::create_record(param_id, material_id, text).1)
+        }
+        ssbh_lib::formats::matl::ParamV15::Sampler(val) => {
+            Box::new(SamplerRecord::create_record(param_id, material_id, val).1)
+        }
+        ssbh_lib::formats::matl::ParamV15::UvTransform(val) => {
+            Box::new(UvTransformRecord::create_record(param_id, material_id, val).1)
+        }
+        ssbh_lib::formats::matl::ParamV15::BlendState(val) => {
+            Box::new(BlendStateV15Record::create_record(param_id, material_id, val).1)
+        }
+        ssbh_lib::formats::matl::ParamV15::RasterizerState(val) => {
+            Box::new(RasterizerV15Record::create_record(param_id, material_id, val).1)
+        }
+        ssbh_lib::formats::matl::ParamV15::Unk7(val) => {
+            Box::new(Color4fRecord::create_record(param_id, material_id, val).1)
+        }
+    }
+}
+
+fn attribute_name_v8(usage: &ssbh_lib::formats::mesh::AttributeUsageV8, sub_index: u32) -> String {
+    // Version 1.8 doesn't store attribute names.
+    // Use the same generated names as ssbh_data like "Position0" or "colorSet1".
+    let prefix = match usage {
+        ssbh_lib::formats::mesh::AttributeUsageV8::Position => "Position",
+        ssbh_lib::formats::mesh::AttributeUsageV8::Normal => "Normal",
+        ssbh_lib::formats::mesh::AttributeUsageV8::Tangent => "Tangent",
+        ssbh_lib::formats::mesh::AttributeUsageV8::TextureCoordinate => "TextureCoordinate",
+        ssbh_lib::formats::mesh::AttributeUsageV8::ColorSet => "colorSet",
+    };
+    format!("{}{}", prefix, sub_index)
+}
+
+fn attribute_name_v9(
+    name: &ssbh_lib::SsbhString,
+    attribute_names: &ssbh_lib::SsbhArray<ssbh_lib::SsbhString>,
+) -> String {
+    // The first name is the one used by the shaders.
+    // Fall back to the attribute's own name if there are no names.
+    attribute_names
+        .elements
+        .first()
+        .unwrap_or(name)
+        .to_string_lossy()
+}
+
+fn attribute_name_records(
+    mesh_attribute_id: i64,
+    attribute_names: &ssbh_lib::SsbhArray<ssbh_lib::SsbhString>,
+) -> Vec<Box<dyn SqlInsert>> {
+    attribute_names
+        .elements
+        .iter()
+        .enumerate()
+        .map(|(i, name)| {
+            let record: Box<dyn SqlInsert> = Box::new(
+                MeshAttributeNameRecord::create_record(
+                    mesh_attribute_id,
+                    i as i64,
+                    name.to_string_lossy(),
+                )
+                .1,
+            );
+            record
+        })
+        .collect()
+}
+
 fn process_mesh(
     mesh: &ssbh_lib::formats::mesh::Mesh,
     file_name: &str,
     directory_id: String,
+    vertex_stats: bool,
+    warnings: &mut Vec<String>,
 ) -> Vec<Box<dyn SqlInsert>> {
     let mut records: Vec<Box<dyn SqlInsert>> = Vec::new();
 
-    let (mesh_id, mesh_record) = MeshRecord::create_record(directory_id, file_name.to_string());
+    let version = format!("{}.{}", mesh.major_version, mesh.minor_version);
+    let (mesh_id, mesh_record) =
+        MeshRecord::create_record(directory_id, file_name.to_string(), version);
     records.push(Box::new(mesh_record));
 
-    for object in &mesh.objects.elements {
-        let mesh_name = object.name.to_string_lossy().to_string();
-        let sub_index = object.sub_index;
+    // Rigging groups are matched to mesh objects by name and sub index.
+    let rigging_groups: HashMap<_, _> = mesh
+        .rigging_buffers
+        .elements
+        .iter()
+        .map(|g| {
+            (
+                (
+                    g.mesh_object_name.to_string_lossy(),
+                    g.mesh_object_sub_index as i64,
+                ),
+                g,
+            )
+        })
+        .collect();
+
+    // Store the attribute IDs by name to link the vertex attribute stats.
+    let mut mesh_objects = Vec::new();
+
+    for (i, object) in mesh.objects.elements.iter().enumerate() {
+        let mesh_name = object.name.to_string_lossy();
+        let sub_index = object.sub_index as i64;
+
+        let rigging_group = rigging_groups.get(&(mesh_name, sub_index));
+        let rigging_flags = rigging_group.map(|g| &g.flags);
 
         let (mesh_object_id, mesh_object_record) =
-            MeshObjectRecord::create_record(mesh_id, mesh_name, sub_index as i64);
+            MeshObjectRecord::create_record(mesh_id, i as i64, object, rigging_flags);
         records.push(Box::new(mesh_object_record));
+        let mut attribute_ids = HashMap::new();
+
+        if let Some(group) = rigging_group {
+            for buffer in &group.buffers.elements {
+                // Version 1.10 stores each weight as a u16 vertex index and a f32 weight.
+                let influence_count = match &buffer.data {
+                    ssbh_lib::formats::mesh::VertexWeights::VertexWeightsV8(v) => v.elements.len(),
+                    ssbh_lib::formats::mesh::VertexWeights::VertexWeightsV9(v) => v.elements.len(),
+                    ssbh_lib::formats::mesh::VertexWeights::VertexWeightsV10(v) => {
+                        v.elements.len() / 6
+                    }
+                };
+                records.push(Box::new(
+                    MeshRiggingGroupRecord::create_record(
+                        mesh_object_id,
+                        buffer.bone_name.to_string_lossy(),
+                        influence_count as i64,
+                    )
+                    .1,
+                ));
+            }
+        }
+
+        match &object.attributes {
+            ssbh_lib::formats::mesh::MeshAttributes::AttributesV8(v) => {
+                for attribute in &v.elements {
+                    let attribute_name = attribute_name_v8(&attribute.usage, attribute.sub_index);
+                    let (mesh_attribute_id, attribute_record) = MeshAttributeRecord::create_record(
+                        mesh_object_id,
+                        attribute_name.clone(),
+                        format!("{:?}", attribute.usage),
+                        format!("{:?}", attribute.data_type),
+                        attribute.buffer_index,
+                        attribute.buffer_offset,
+                        attribute.sub_index as i64,
+                    );
+                    records.push(Box::new(attribute_record));
+                    attribute_ids
+                        .entry(attribute_name)
+                        .or_insert(mesh_attribute_id);
+                }
+            }
+            ssbh_lib::formats::mesh::MeshAttributes::AttributesV9(v) => {
+                for attribute in &v.elements {
+                    let attribute_name =
+                        attribute_name_v9(&attribute.name, &attribute.attribute_names);
+                    let (mesh_attribute_id, attribute_record) = MeshAttributeRecord::create_record(
+                        mesh_object_id,
+                        attribute_name.clone(),
+                        format!("{:?}", attribute.usage),
+                        format!("{:?}", attribute.data_type),
+                        attribute.buffer_index,
+                        attribute.buffer_offset,
+                        attribute.sub_index as i64,
+                    );
+                    records.push(Box::new(attribute_record));
+                    attribute_ids
+                        .entry(attribute_name)
+                        .or_insert(mesh_attribute_id);
+                    records.append(&mut attribute_name_records(
+                        mesh_attribute_id,
+                        &attribute.attribute_names,
+                    ));
+                }
+            }
+            ssbh_lib::formats::mesh::MeshAttributes::AttributesV10(v) => {
+                for attribute in &v.elements {
+                    let attribute_name =
+                        attribute_name_v9(&attribute.name, &attribute.attribute_names);
+                    let (mesh_attribute_id, attribute_record) = MeshAttributeRecord::create_record(
+                        mesh_object_id,
+                        attribute_name.clone(),
+                        format!("{:?}", attribute.usage),
+                        format!("{:?}", attribute.data_type),
+                        attribute.buffer_index,
+                        attribute.buffer_offset,
+                        attribute.sub_index as i64,
+                    );
+                    records.push(Box::new(attribute_record));
+                    attribute_ids
+                        .entry(attribute_name)
+                        .or_insert(mesh_attribute_id);
+                    records.append(&mut attribute_name_records(
+                        mesh_attribute_id,
+                        &attribute.attribute_names,
+                    ));
+                }
+            }
+        }
+
+        mesh_objects.push((mesh_object_id, attribute_ids));
+    }
 
-        // Only version 1.10 has attribute names.
-        // There are a small number of 1.8 meshes, so ignore them for now.
-        if let ssbh_lib::formats::mesh::MeshAttributes::AttributesV10(v) = &object.attributes {
-            for attribute in &v.elements {
-                let attribute_name = attribute.attribute_names.elements[0].to_string_lossy();
+    if vertex_stats {
+        records.append(&mut process_vertex_stats(mesh, &mesh_objects, warnings));
+    }
+
+    records
+}
+
+fn process_vertex_stats(
+    mesh: &ssbh_lib::formats::mesh::Mesh,
+    mesh_objects: &[(i64, HashMap<String, i64>)],
+    warnings: &mut Vec<String>,
+) -> Vec<Box<dyn SqlInsert>> {
+    let mut records: Vec<Box<dyn SqlInsert>> = Vec::new();
+
+    // The vertex buffers use many different data types, so use ssbh_data to decode the values.
+    let mesh_data = match ssbh_data::mesh_data::MeshData::try_from(mesh) {
+        Ok(mesh_data) => mesh_data,
+        Err(e) => {
+            warnings.push(format!("Failed to decode vertex data: {:?}", e));
+            return records;
+        }
+    };
+
+    // The decoded objects are in the same order as the mesh objects.
+    // The decoded attributes use the same names as the MeshAttribute records.
+    for (object, (mesh_object_id, attribute_ids)) in mesh_data.objects.iter().zip(mesh_objects) {
+        let attributes = object
+            .positions
+            .iter()
+            .chain(object.normals.iter())
+            .chain(object.binormals.iter())
+            .chain(object.tangents.iter())
+            .chain(object.texture_coordinates.iter())
+            .chain(object.color_sets.iter());
+
+        for attribute in attributes {
+            let stats = match &attribute.data {
+                ssbh_data::mesh_data::VectorData::Vector2(v) => component_stats(v),
+                ssbh_data::mesh_data::VectorData::Vector3(v) => component_stats(v),
+                ssbh_data::mesh_data::VectorData::Vector4(v) => component_stats(v),
+            };
+
+            for (component, (min, max, mean)) in stats.into_iter().enumerate() {
                 records.push(Box::new(
-                    MeshAttributeRecord::create_record(mesh_object_id, attribute_name).1,
+                    VertexAttributeStatsRecord::create_record(
+                        *mesh_object_id,
+                        attribute_ids.get(&attribute.name).copied(),
+                        attribute.name.clone(),
+                        component as i64,
+                        min,
+                        max,
+                        mean,
+                    )
+                    .1,
                 ));
             }
         }
@@ -708,21 +602,501 @@ fn process_mesh(
     records
 }
 
+/// Calculate the min, max, and mean for each component or an empty list if there are no values.
+fn component_stats<const N: usize>(values: &[[f32; N]]) -> Vec<(f64, f64, f64)> {
+    if values.is_empty() {
+        return Vec::new();
+    }
+
+    (0..N)
+        .map(|i| {
+            let (min, max, sum) = values.iter().map(|v| v[i] as f64).fold(
+                (f64::INFINITY, f64::NEG_INFINITY, 0.0),
+                |(min, max, sum), x| (min.min(x), max.max(x), sum + x),
+            );
+            (min, max, sum / values.len() as f64)
+        })
+        .collect()
+}
+
 fn process_modl(
     modl: &ssbh_lib::formats::modl::Modl,
     file_name: &str,
     directory_id: String,
-) -> ModlRecord {
-    // There could be multiple material filenames but assume just one.
-    // Most modl files only reference a single material.
-    ModlRecord::create_record(
+) -> Vec<Box<dyn SqlInsert>> {
+    let mut records: Vec<Box<dyn SqlInsert>> = Vec::new();
+
+    let (modl_id, modl_record) = ModlRecord::create_record(
         directory_id,
         file_name.to_string(),
         modl.model_name.to_string_lossy(),
         modl.skeleton_file_name.to_string_lossy(),
-        modl.material_file_names.elements[0].to_string_lossy(),
-    )
-    .1
+        modl.material_file_names
+            .elements
+            .first()
+            .map(|f| f.to_string_lossy()),
+        modl.mesh_file_name.to_string_lossy(),
+    );
+    records.push(Box::new(modl_record));
+
+    // Most modl files only reference a single material, but some reference multiple or none.
+    for (i, material_file_name) in modl.material_file_names.elements.iter().enumerate() {
+        records.push(Box::new(
+            ModlMaterialFileRecord::create_record(
+                modl_id,
+                i as i64,
+                material_file_name.to_string_lossy(),
+            )
+            .1,
+        ));
+    }
+
+    // The mesh object and material IDs are resolved after all files are inserted.
+    // The mesh and matl may not have been processed yet.
+    for entry in &modl.entries.elements {
+        records.push(Box::new(
+            ModlEntryRecord::create_record(
+                modl_id,
+                entry.mesh_object_name.to_string_lossy(),
+                entry.mesh_object_sub_index as i64,
+                entry.material_label.to_string_lossy(),
+            )
+            .1,
+        ));
+    }
+
+    records
+}
+
+fn process_skel(
+    skel: &ssbh_lib::formats::skel::Skel,
+    file_name: &str,
+    directory: String,
+) -> Vec<Box<dyn SqlInsert>> {
+    let mut records: Vec<Box<dyn SqlInsert>> = Vec::new();
+
+    let (skel_id, skel_record) = SkelRecord::create_record(directory, file_name.to_string());
+    records.push(Box::new(skel_record));
+
+    // The transform arrays have one matrix for each bone entry.
+    for ((bone, transform), world_transform) in skel
+        .bone_entries
+        .elements
+        .iter()
+        .zip(skel.transforms.elements.iter())
+        .zip(skel.world_transforms.elements.iter())
+    {
+        records.push(Box::new(
+            BoneRecord::create_record(skel_id, bone, transform, world_transform).1,
+        ));
+    }
+
+    records
+}
+
+fn process_hlpb(
+    hlpb: &ssbh_lib::formats::hlpb::Hlpb,
+    file_name: &str,
+    directory: String,
+) -> Vec<Box<dyn SqlInsert>> {
+    let mut records: Vec<Box<dyn SqlInsert>> = Vec::new();
+
+    let (hlpb_id, hlpb_record) = HlpbRecord::create_record(directory, file_name.to_string());
+    records.push(Box::new(hlpb_record));
+
+    for constraint in &hlpb.aim_entries.elements {
+        records.push(Box::new(
+            HlpbAimConstraintRecord::create_record(hlpb_id, constraint).1,
+        ));
+    }
+
+    for constraint in &hlpb.interpolation_entries.elements {
+        records.push(Box::new(
+            HlpbOrientConstraintRecord::create_record(hlpb_id, constraint).1,
+        ));
+    }
+
+    records
+}
+
+fn process_nufx(
+    nufx: &ssbh_lib::formats::nufx::Nufx,
+    file_name: &str,
+    directory: String,
+) -> Vec<Box<dyn SqlInsert>> {
+    let mut records: Vec<Box<dyn SqlInsert>> = Vec::new();
+
+    let (nufx_id, nufx_record) = NufxRecord::create_record(directory, file_name.to_string());
+    records.push(Box::new(nufx_record));
+
+    match &nufx.programs {
+        // Version 1.0 programs don't have vertex attributes.
+        ssbh_lib::formats::nufx::ShaderPrograms::ProgramsV0(programs) => {
+            for program in &programs.elements {
+                let (shader_program_id, program_record) = ShaderProgramRecord::create_record(
+                    nufx_id,
+                    program.name.to_string_lossy(),
+                    program.render_pass.to_string_lossy(),
+                    &program.shaders,
+                );
+                records.push(Box::new(program_record));
+
+                records.append(&mut process_shader_program_params(
+                    shader_program_id,
+                    &program.material_parameters.elements,
+                ));
+            }
+        }
+        ssbh_lib::formats::nufx::ShaderPrograms::ProgramsV1(programs) => {
+            for program in &programs.elements {
+                let (shader_program_id, program_record) = ShaderProgramRecord::create_record(
+                    nufx_id,
+                    program.name.to_string_lossy(),
+                    program.render_pass.to_string_lossy(),
+                    &program.shaders,
+                );
+                records.push(Box::new(program_record));
+
+                for attribute in &program.vertex_attributes.elements {
+                    records.push(Box::new(
+                        ShaderProgramVertexAttributeRecord::create_record(
+                            shader_program_id,
+                            attribute.name.to_string_lossy(),
+                            attribute.attribute_name.to_string_lossy(),
+                        )
+                        .1,
+                    ));
+                }
+
+                records.append(&mut process_shader_program_params(
+                    shader_program_id,
+                    &program.material_parameters.elements,
+                ));
+            }
+        }
+    }
+
+    records
+}
+
+fn process_shader_program_params(
+    shader_program_id: i64,
+    parameters: &[ssbh_lib::formats::nufx::MaterialParameter],
+) -> Vec<Box<dyn SqlInsert>> {
+    let mut records: Vec<Box<dyn SqlInsert>> = Vec::new();
+
+    for parameter in parameters {
+        records.push(Box::new(
+            ShaderProgramMaterialParamRecord::create_record(
+                shader_program_id,
+                // SQLite integers are signed 64-bit, so keep all the bits of the ID.
+                parameter.param_id as i64,
+                parameter.parameter_name.to_string_lossy(),
+            )
+            .1,
+        ));
+    }
+
+    records
+}
+
+fn process_nrpd(
+    nrpd: &ssbh_lib::formats::nrpd::Nrpd,
+    file_name: &str,
+    directory: String,
+) -> Vec<Box<dyn SqlInsert>> {
+    let mut records: Vec<Box<dyn SqlInsert>> = Vec::new();
+
+    let (nrpd_id, nrpd_record) = NrpdRecord::create_record(directory, file_name.to_string());
+    records.push(Box::new(nrpd_record));
+
+    for container in &nrpd.frame_buffer_containers.elements {
+        // Only some frame buffer types have dimensions.
+        let frame_buffer_type = container.frame_buffer.data_type as u32;
+        let (name, width, height) = match container.frame_buffer.data.as_ref() {
+            Some(ssbh_lib::formats::nrpd::FrameBuffer::Framebuffer0(f)) => {
+                (f.name.to_string_lossy(), Some(f.width), Some(f.height))
+            }
+            Some(ssbh_lib::formats::nrpd::FrameBuffer::Framebuffer1(f)) => {
+                (f.name.to_string_lossy(), Some(f.width), Some(f.height))
+            }
+            Some(ssbh_lib::formats::nrpd::FrameBuffer::Framebuffer2(f)) => {
+                (f.name.to_string_lossy(), Some(f.width), Some(f.height))
+            }
+            Some(ssbh_lib::formats::nrpd::FrameBuffer::Framebuffer3(f)) => {
+                (f.name.to_string_lossy(), None, None)
+            }
+            Some(ssbh_lib::formats::nrpd::FrameBuffer::Framebuffer4(f)) => {
+                (f.name.to_string_lossy(), None, None)
+            }
+            None => continue,
+        };
+
+        records.push(Box::new(
+            NrpdFrameBufferRecord::create_record(nrpd_id, name, frame_buffer_type, width, height).1,
+        ));
+    }
+
+    for container in &nrpd.state_containers.elements {
+        let state = match container.state.data.as_ref() {
+            Some(state) => state,
+            None => continue,
+        };
+
+        let name = match state {
+            ssbh_lib::formats::nrpd::NrpdState::Sampler(state) => state.name.to_string_lossy(),
+            ssbh_lib::formats::nrpd::NrpdState::RasterizerState(state) => {
+                state.name.to_string_lossy()
+            }
+            ssbh_lib::formats::nrpd::NrpdState::DepthState(state) => state.name.to_string_lossy(),
+            ssbh_lib::formats::nrpd::NrpdState::BlendState(state) => state.name.to_string_lossy(),
+        };
+
+        let (state_container_id, container_record) = NrpdStateContainerRecord::create_record(
+            nrpd_id,
+            name,
+            container.state.data_type as u32,
+        );
+        records.push(Box::new(container_record));
+
+        let state_record: Box<dyn SqlInsert> = match state {
+            ssbh_lib::formats::nrpd::NrpdState::Sampler(state) => {
+                Box::new(NrpdSamplerRecord::create_record(state_container_id, state).1)
+            }
+            ssbh_lib::formats::nrpd::NrpdState::RasterizerState(state) => {
+                Box::new(NrpdRasterizerStateRecord::create_record(state_container_id, state).1)
+            }
+            ssbh_lib::formats::nrpd::NrpdState::DepthState(state) => {
+                Box::new(NrpdDepthStateRecord::create_record(state_container_id, state).1)
+            }
+            ssbh_lib::formats::nrpd::NrpdState::BlendState(state) => {
+                Box::new(NrpdBlendStateRecord::create_record(state_container_id, state).1)
+            }
+        };
+        records.push(state_record);
+    }
+
+    records
+}
+
+fn process_shdr(
+    shdr: &ssbh_lib::formats::shdr::Shdr,
+    file_name: &str,
+    directory: String,
+) -> Vec<Box<dyn SqlInsert>> {
+    let mut records: Vec<Box<dyn SqlInsert>> = Vec::new();
+
+    let (shdr_id, shdr_record) = ShdrRecord::create_record(directory, file_name.to_string());
+    records.push(Box::new(shdr_record));
+
+    // Skip the shader binaries to avoid greatly increasing the database size.
+    for shader in &shdr.shaders.elements {
+        records.push(Box::new(ShaderRecord::create_record(shdr_id, shader).1));
+    }
+
+    records
+}
+
+fn process_anim(
+    anim: &ssbh_lib::formats::anim::Anim,
+    file_name: &str,
+    directory: String,
+    warnings: &mut Vec<String>,
+) -> Vec<Box<dyn SqlInsert>> {
+    let (anim_id, mut records) = match &anim.header {
+        // Version 1.2 doesn't have groups, so only store the anim itself.
+        // Smash Ultimate only uses versions 2.0 and 2.1.
+        ssbh_lib::formats::anim::AnimHeader::HeaderV1(header) => process_anim_groups(
+            &[],
+            header.name.to_string_lossy(),
+            header.final_frame_index as f64,
+            file_name,
+            directory,
+        ),
+        ssbh_lib::formats::anim::AnimHeader::HeaderV20(header) => process_anim_groups(
+            &header.groups.elements,
+            header.name.to_string_lossy(),
+            header.final_frame_index as f64,
+            file_name,
+            directory,
+        ),
+        ssbh_lib::formats::anim::AnimHeader::HeaderV21(header) => process_anim_groups(
+            &header.groups.elements,
+            header.name.to_string_lossy(),
+            header.final_frame_index as f64,
+            file_name,
+            directory,
+        ),
+    };
+
+    records.append(&mut process_anim_material_values(anim, anim_id, warnings));
+
+    records
+}
+
+fn process_anim_groups(
+    groups: &[ssbh_lib::formats::anim::AnimGroup],
+    name: String,
+    final_frame_index: f64,
+    file_name: &str,
+    directory: String,
+) -> (i64, Vec<Box<dyn SqlInsert>>) {
+    let mut records: Vec<Box<dyn SqlInsert>> = Vec::new();
+
+    let (anim_id, anim_record) =
+        AnimRecord::create_record(directory, file_name.to_string(), name, final_frame_index);
+    records.push(Box::new(anim_record));
+
+    for group in groups {
+        let (anim_group_id, group_record) =
+            AnimGroupRecord::create_record(anim_id, group.group_type as u32);
+        records.push(Box::new(group_record));
+
+        for node in &group.nodes.elements {
+            let (anim_node_id, node_record) =
+                AnimNodeRecord::create_record(anim_group_id, node.name.to_string_lossy());
+            records.push(Box::new(node_record));
+
+            for track in &node.tracks.elements {
+                records.push(Box::new(
+                    AnimTrackRecord::create_record(anim_node_id, track).1,
+                ));
+            }
+        }
+    }
+
+    (anim_id, records)
+}
+
+fn process_anim_material_values(
+    anim: &ssbh_lib::formats::anim::Anim,
+    anim_id: i64,
+    warnings: &mut Vec<String>,
+) -> Vec<Box<dyn SqlInsert>> {
+    let mut records: Vec<Box<dyn SqlInsert>> = Vec::new();
+
+    // The track data is compressed, so use ssbh_data to decode the values for each frame.
+    let anim_data = match ssbh_data::anim_data::AnimData::try_from(anim) {
+        Ok(anim_data) => anim_data,
+        Err(_) => return records,
+    };
+
+    for group in anim_data
+        .groups
+        .iter()
+        .filter(|g| g.group_type == ssbh_data::anim_data::GroupType::Material)
+    {
+        // Material nodes are named after the material label.
+        // Material tracks are named after the param like "CustomVector6".
+        for node in &group.nodes {
+            for track in &node.tracks {
+                let values: Vec<(&str, Vec<f64>)> = match &track.values {
+                    ssbh_data::anim_data::TrackValues::Vector4(values) => values
+                        .iter()
+                        .map(|v| {
+                            (
+                                "Vector4",
+                                vec![v.x as f64, v.y as f64, v.z as f64, v.w as f64],
+                            )
+                        })
+                        .collect(),
+                    ssbh_data::anim_data::TrackValues::Float(values) => {
+                        values.iter().map(|v| ("Float", vec![*v as f64])).collect()
+                    }
+                    ssbh_data::anim_data::TrackValues::Boolean(values) => values
+                        .iter()
+                        .map(|v| ("Boolean", vec![if *v { 1.0 } else { 0.0 }]))
+                        .collect(),
+                    ssbh_data::anim_data::TrackValues::PatternIndex(values) => values
+                        .iter()
+                        .map(|v| ("PatternIndex", vec![*v as f64]))
+                        .collect(),
+                    ssbh_data::anim_data::TrackValues::UvTransform(values) => values
+                        .iter()
+                        .map(|v| {
+                            (
+                                "UvTransform",
+                                vec![
+                                    v.scale_u as f64,
+                                    v.scale_v as f64,
+                                    v.rotation as f64,
+                                    v.translate_u as f64,
+                                    v.translate_v as f64,
+                                ],
+                            )
+                        })
+                        .collect(),
+                    // Transform tracks are for bones and cameras rather than material params.
+                    ssbh_data::anim_data::TrackValues::Transform(_) => {
+                        warnings.push(format!(
+                            "Skipped Transform track {:?} for material {:?}",
+                            track.name, node.name
+                        ));
+                        Vec::new()
+                    }
+                };
+
+                for (frame, (value_type, value)) in values.iter().enumerate() {
+                    records.push(Box::new(
+                        AnimMaterialValueRecord::create_record(
+                            anim_id,
+                            node.name.clone(),
+                            track.name.clone(),
+                            frame as i64,
+                            value_type,
+                            value,
+                        )
+                        .1,
+                    ));
+                }
+            }
+        }
+    }
+
+    records
+}
+
+fn process_adj(
+    adj: &ssbh_lib::formats::adj::Adj,
+    file_name: &str,
+    directory: String,
+) -> Vec<Box<dyn SqlInsert>> {
+    let mut records: Vec<Box<dyn SqlInsert>> = Vec::new();
+
+    let (adj_id, adj_record) = AdjRecord::create_record(directory, file_name.to_string());
+    records.push(Box::new(adj_record));
+
+    // The index buffer offsets are in bytes.
+    // Each entry's indices end where the entry with the next largest offset begins.
+    let mut items: Vec<_> = adj.items.iter().collect();
+    items.sort_by_key(|item| item.buffer_offset);
+
+    let index_buffer_size = (adj.buffer.len() * std::mem::size_of::<i16>()) as u32;
+    for (i, item) in items.iter().enumerate() {
+        let end = items
+            .get(i + 1)
+            .map(|next| next.buffer_offset)
+            .unwrap_or(index_buffer_size)
+            .min(index_buffer_size);
+
+        // Skip entries with offsets past the end of the index buffer.
+        let index_count = match end.checked_sub(item.buffer_offset) {
+            Some(size) => size as usize / std::mem::size_of::<i16>(),
+            None => continue,
+        };
+
+        records.push(Box::new(
+            AdjEntryRecord::create_record(
+                adj_id,
+                item.mesh_index as i64,
+                item.buffer_offset as i64,
+                index_count as i64,
+            )
+            .1,
+        ));
+    }
+
+    records
 }
 
 fn process_xmb(
@@ -759,15 +1133,21 @@ fn process_ssbh(
     file_name: &str,
     ssbh: &ssbh_lib::Ssbh,
     directory: String,
+    vertex_stats: bool,
+    warnings: &mut Vec<String>,
 ) -> Vec<Box<dyn SqlInsert>> {
     match &ssbh.data {
-        ssbh_lib::SsbhFile::Matl(matl) => process_matl(&matl, directory, file_name.to_string()),
-        ssbh_lib::SsbhFile::Modl(modl) => {
-            let record = process_modl(&modl, file_name, directory);
-            vec![Box::new(record)]
+        ssbh_lib::SsbhFile::Matl(matl) => process_matl(matl, directory, file_name.to_string()),
+        ssbh_lib::SsbhFile::Modl(modl) => process_modl(modl, file_name, directory),
+        ssbh_lib::SsbhFile::Mesh(mesh) => {
+            process_mesh(mesh, file_name, directory, vertex_stats, warnings)
         }
-        ssbh_lib::SsbhFile::Mesh(mesh) => process_mesh(&mesh, file_name, directory),
-        _ => (Vec::<Box<dyn SqlInsert>>::new()),
+        ssbh_lib::SsbhFile::Skel(skel) => process_skel(skel, file_name, directory),
+        ssbh_lib::SsbhFile::Anim(anim) => process_anim(anim, file_name, directory, warnings),
+        ssbh_lib::SsbhFile::Hlpb(hlpb) => process_hlpb(hlpb, file_name, directory),
+        ssbh_lib::SsbhFile::Nufx(nufx) => process_nufx(nufx, file_name, directory),
+        ssbh_lib::SsbhFile::Nrpd(nrpd) => process_nrpd(nrpd, file_name, directory),
+        ssbh_lib::SsbhFile::Shdr(shdr) => process_shdr(shdr, file_name, directory),
     }
 }
 
@@ -785,51 +1165,105 @@ fn get_directory(file_path: &Path, source_folder: &Path) -> String {
         .to_string()
 }
 
-fn get_records(file_path: &Path, source_folder: &Path) -> Vec<Box<dyn SqlInsert>> {
+/// The records for a file and any warnings for data that was skipped.
+type FileRecords = (Vec<Box<dyn SqlInsert>>, Vec<String>);
+
+/// Get the records and any warnings for the file or the parse error.
+fn get_records(
+    file_path: &Path,
+    source_folder: &Path,
+    vertex_stats: bool,
+) -> std::result::Result<FileRecords, String> {
     let file_name = file_path.file_name().unwrap().to_str().unwrap();
     let extension = file_path.extension().unwrap().to_str().unwrap();
 
-    let mut records: Vec<Box<dyn SqlInsert>> = Vec::new();
-
     let directory = get_directory(file_path, source_folder);
 
+    let mut warnings = Vec::new();
+
+    // Return the error text since the errors can't be sent between threads.
     match extension {
         "xmb" => {
-            if let Ok(xmb) = xmb_lib::read_xmb(file_path) {
-                let mut xmb_records = process_xmb(file_name, &xmb, directory);
-                records.append(&mut xmb_records);
-            }
+            let xmb = xmb_lib::read_xmb(file_path).map_err(|e| format!("{:?}", e))?;
+            Ok((process_xmb(file_name, &xmb, directory), warnings))
         }
-        // Assume files that are not XMB files are SSBH.
+        "adjb" => {
+            let adj = ssbh_lib::formats::adj::Adj::from_file(file_path)
+                .map_err(|e| format!("{:?}", e))?;
+            Ok((process_adj(&adj, file_name, directory), warnings))
+        }
+        // Assume the remaining files are SSBH.
         _ => {
-            if let Ok(ssbh) = ssbh_lib::Ssbh::from_file(file_path) {
-                let mut ssbh_records = process_ssbh(file_name, &ssbh, directory);
-                records.append(&mut ssbh_records);
-            }
+            let ssbh = ssbh_lib::Ssbh::from_file(file_path).map_err(|e| format!("{:?}", e))?;
+            let records = process_ssbh(file_name, &ssbh, directory, vertex_stats, &mut warnings);
+            Ok((records, warnings))
         }
     }
+}
 
-    records
+/// Messages for each file to print at the end of the run.
+type FileMessages = Vec<(PathBuf, String)>;
+
+/// The problems found while processing the files to print at the end of the run.
+struct FileReport {
+    parse_errors: FileMessages,
+    warnings: FileMessages,
+    /// The number of references for each param ID without a known name.
+    unknown_params: BTreeMap<i64, i64>,
+}
+
+fn print_file_messages(description: &str, messages: &mut [(PathBuf, String)]) {
+    if messages.is_empty() {
+        return;
+    }
+
+    // Sort by path to group the messages for each file.
+    messages.sort_by(|a, b| a.0.cmp(&b.0));
+
+    println!("{} ({}):", description, messages.len());
+    for (path, message) in messages.iter() {
+        println!("{:?}: {}", path, message);
+    }
 }
 
-fn process_files(source_folder: &Path, connection: &mut Connection) -> Result<()> {
+fn process_files(
+    source_folder: &Path,
+    connection: &mut Connection,
+    vertex_stats: bool,
+) -> Result<FileReport> {
     let parse_duration = Instant::now();
 
     let paths_iter = globwalk::GlobWalkerBuilder::from_patterns(
         source_folder,
-        &["*.{numatb,numdlb,numshb,xmb}"],
+        &["*.{numatb,numdlb,numshb,nusktb,nuanmb,nuhlpb,nufxlb,nurpdb,nushdb,adjb,xmb}"],
     )
     .build()
     .unwrap()
-    .into_iter()
     .filter_map(Result::ok)
     .par_bridge();
 
-    let records: Vec<Box<dyn SqlInsert>> = paths_iter
-        .map(|p| get_records(p.path(), source_folder))
-        .flatten()
+    let results: Vec<_> = paths_iter
+        .map(|p| {
+            (
+                p.path().to_path_buf(),
+                get_records(p.path(), source_folder, vertex_stats),
+            )
+        })
         .collect();
 
+    let mut records: Vec<Box<dyn SqlInsert>> = Vec::new();
+    let mut parse_errors = Vec::new();
+    let mut warnings = Vec::new();
+    for (path, result) in results {
+        match result {
+            Ok((mut file_records, file_warnings)) => {
+                records.append(&mut file_records);
+                warnings.extend(file_warnings.into_iter().map(|w| (path.clone(), w)));
+            }
+            Err(e) => parse_errors.push((path, e)),
+        }
+    }
+
     println!(
         "Create {:?} records: {:?}",
         records.len(),
@@ -841,6 +1275,10 @@ fn process_files(source_folder: &Path, connection: &mut Connection) -> Result<()
     let database_duration = Instant::now();
     let mut transaction = connection.transaction()?;
 
+    // Nufx param IDs may not have a CustomParam row until the placeholders are added.
+    // Check the foreign keys when committing instead of for each insert.
+    transaction.execute_batch("PRAGMA defer_foreign_keys = ON;")?;
+
     for record in &records {
         match record.insert(&mut transaction) {
             Ok(_) => (),
@@ -851,6 +1289,8 @@ fn process_files(source_folder: &Path, connection: &mut Connection) -> Result<()
         }
     }
 
+    let unknown_params = insert_unknown_params(&mut transaction)?;
+
     transaction.commit()?;
     println!(
         "Write {} records to database: {:?}",
@@ -858,28 +1298,70 @@ fn process_files(source_folder: &Path, connection: &mut Connection) -> Result<()
         database_duration.elapsed()
     );
 
-    Ok(())
+    Ok(FileReport {
+        parse_errors,
+        warnings,
+        unknown_params,
+    })
+}
+
+fn create_table_indexes<T: SqlSchema>(transaction: &Transaction) -> Result<()> {
+    transaction.execute_batch(T::create_indexes())
 }
 
 fn create_indexes(connection: &mut Connection) -> Result<()> {
     let transaction = connection.transaction()?;
 
-    // Create indexes to optimize only the more commonly specified parameters.
+    // Index all foreign keys as well as the more commonly searched columns.
+    // The indexes are generated from the attributes on each record type.
+    for_each_record!(create_table_indexes, &transaction);
+
+    transaction.commit()
+}
+
+fn link_modl_entries(connection: &mut Connection) -> Result<()> {
+    let transaction = connection.transaction()?;
+
+    // The mesh and matl referenced by a modl are in the same directory as the modl.
+    transaction.execute_batch(
+        "UPDATE ModlEntry SET MeshObjectId = (
+            SELECT MeshObject.Id FROM MeshObject
+            INNER JOIN Mesh ON Mesh.Id = MeshObject.MeshId
+            INNER JOIN Modl ON Modl.Directory = Mesh.Directory AND Modl.MeshFileName = Mesh.FileName
+            WHERE Modl.Id = ModlEntry.ModlId
+                AND MeshObject.Name = ModlEntry.MeshObjectName
+                AND MeshObject.SubIndex = ModlEntry.MeshObjectSubIndex
+        );
+        UPDATE ModlEntry SET MaterialId = (
+            SELECT Material.Id FROM Modl
+            INNER JOIN ModlMaterialFile ON ModlMaterialFile.ModlId = Modl.Id
+            INNER JOIN Matl ON Matl.Directory = Modl.Directory AND Matl.FileName = ModlMaterialFile.FileName
+            INNER JOIN Material ON Material.MatlId = Matl.Id
+            WHERE Modl.Id = ModlEntry.ModlId
+                AND Material.MaterialLabel = ModlEntry.MaterialLabel
+            ORDER BY ModlMaterialFile.FileIndex
+        );",
+    )?;
+
+    transaction.commit()
+}
+
+fn link_adj_entries(connection: &mut Connection) -> Result<()> {
+    let transaction = connection.transaction()?;
+
+    // The adjb has the same name as the mesh in the same directory like "model.adjb" and "model.numshb".
     transaction.execute_batch(
-        "CREATE INDEX BlendState_MaterialId_Idx ON BlendState(MaterialId);
-        CREATE INDEX CustomBooleanParam_MaterialId_Idx ON CustomBooleanParam(MaterialId);
-        CREATE INDEX CustomFloatParam_MaterialId_Idx ON CustomFloatParam(MaterialId);
-        CREATE INDEX CustomVectorParam_MaterialId_Idx ON CustomVectorParam(MaterialId);
-        CREATE INDEX RasterizerState_MaterialId_Idx ON RasterizerState(MaterialId);
-        CREATE INDEX Sampler_MaterialId_Idx ON Sampler(MaterialId);
-        CREATE INDEX Texture_MaterialId_Idx ON Texture(MaterialId);
-        CREATE INDEX BlendState_ParamId_Idx ON BlendState(ParamId);
-        CREATE INDEX CustomBooleanParam_ParamId_Idx ON CustomBooleanParam(ParamId);
-        CREATE INDEX CustomFloatParam_ParamId_Idx ON CustomFloatParam(ParamId);
-        CREATE INDEX CustomVectorParam_ParamId_Idx ON CustomVectorParam(ParamId);
-        CREATE INDEX RasterizerState_ParamId_Idx ON RasterizerState(ParamId);
-        CREATE INDEX Sampler_ParamId_Idx ON Sampler(ParamId);
-        CREATE INDEX Texture_ParamId_Idx ON Texture(ParamId);",
+        "UPDATE Adj SET MeshId = (
+            SELECT Mesh.Id FROM Mesh
+            WHERE Mesh.Directory = Adj.Directory
+                AND Mesh.FileName = REPLACE(Adj.FileName, '.adjb', '.numshb')
+        );
+        UPDATE AdjEntry SET MeshObjectId = (
+            SELECT MeshObject.Id FROM MeshObject
+            INNER JOIN Adj ON Adj.MeshId = MeshObject.MeshId
+            WHERE Adj.Id = AdjEntry.AdjId
+                AND MeshObject.MeshObjectIndex = AdjEntry.MeshObjectIndex
+        );",
     )?;
 
     transaction.commit()
@@ -889,21 +1371,258 @@ fn initialize_database(connection: &mut Connection) -> Result<()> {
     let mut transaction = connection.transaction()?;
 
     create_tables(&mut transaction)?;
-    insert_custom_params(&transaction)?;
+    insert_custom_params(&mut transaction)?;
 
     transaction.commit()
 }
 
-pub fn create_database(source_folder: &Path, database_path: &Path) -> Result<()> {
+/// Create the database from the files in `source_folder`.
+/// Decoding vertex data to calculate `VertexAttributeStats` is slow, so this is only done if `vertex_stats` is `true`.
+pub fn create_database(
+    source_folder: &Path,
+    database_path: &Path,
+    vertex_stats: bool,
+) -> Result<()> {
     let mut connection = Connection::open(database_path)?;
 
     initialize_database(&mut connection)?;
-    process_files(&source_folder, &mut connection)?;
+    let mut report = process_files(source_folder, &mut connection, vertex_stats)?;
 
     create_indexes(&mut connection)?;
+    set_param_types(&mut connection)?;
+    link_modl_entries(&mut connection)?;
+    link_adj_entries(&mut connection)?;
 
     // Reduce the amount of space used by the file on disk.
     connection.execute_batch("VACUUM;")?;
 
+    print_file_messages("Failed to parse files", &mut report.parse_errors);
+    print_file_messages("Skipped data", &mut report.warnings);
+    print_unknown_params(&report.unknown_params);
+
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn insert_default<T: SqlInsert + SqlSelect + SqlSchema + Default>(
+        transaction: &mut Transaction,
+    ) -> Result<()> {
+        T::default().insert(transaction)?;
+        assert_eq!(1, T::select_all(transaction)?.len(), "{}", T::TABLE_NAME);
+        assert_eq!(1, T::select_where(transaction, "Id", 0)?.len());
+        Ok(())
+    }
+
+    fn push_table_name<T: SqlSchema>(table_names: &mut Vec<&str>) -> Result<()> {
+        table_names.push(T::TABLE_NAME);
+        Ok(())
+    }
+
+    #[test]
+    fn select_where_invalid_column() -> Result<()> {
+        let mut connection = Connection::open_in_memory()?;
+        let transaction = connection.transaction()?;
+        create_table::<SamplerRecord>(&transaction)?;
+
+        let result = SamplerRecord::select_where(&transaction, "MaterialId = 0 OR 1", 0);
+        assert!(matches!(result, Err(rusqlite::Error::InvalidColumnName(_))));
+        Ok(())
+    }
+
+    #[test]
+    fn create_tables_and_insert_default_records() -> Result<()> {
+        let mut connection = Connection::open_in_memory()?;
+        let mut transaction = connection.transaction()?;
+
+        create_tables(&mut transaction)?;
+        for_each_record!(insert_default, &mut transaction);
+        for_each_record!(create_table_indexes, &transaction);
+
+        // Check that each record type creates its own table.
+        let mut expected = Vec::new();
+        for_each_record!(push_table_name, &mut expected);
+        expected.sort_unstable();
+
+        let actual: Vec<String> = transaction
+            .prepare("SELECT name FROM sqlite_master WHERE type = 'table' ORDER BY name")?
+            .query_map([], |row| row.get(0))?
+            .collect::<Result<_>>()?;
+
+        assert_eq!(expected, actual);
+        Ok(())
+    }
+
+    #[test]
+    fn link_modl_entries_same_directory() -> Result<()> {
+        let mut connection = Connection::open_in_memory()?;
+        initialize_database(&mut connection)?;
+
+        let mut transaction = connection.transaction()?;
+        ModlRecord {
+            id: 0,
+            directory: "model/body/c00".to_string(),
+            file_name: "model.numdlb".to_string(),
+            mesh_file_name: "model.numshb".to_string(),
+            ..Default::default()
+        }
+        .insert(&mut transaction)?;
+        MeshRecord {
+            id: 0,
+            directory: "model/body/c00".to_string(),
+            file_name: "model.numshb".to_string(),
+            ..Default::default()
+        }
+        .insert(&mut transaction)?;
+        // A mesh object with the same name in a different mesh shouldn't match.
+        MeshRecord {
+            id: 1,
+            directory: "model/body/c01".to_string(),
+            file_name: "model.numshb".to_string(),
+            ..Default::default()
+        }
+        .insert(&mut transaction)?;
+        for (id, mesh_id, sub_index) in [(0, 1, 0), (1, 0, 0), (2, 0, 1)] {
+            MeshObjectRecord {
+                id,
+                mesh_id,
+                name: "body".to_string(),
+                sub_index,
+                ..Default::default()
+            }
+            .insert(&mut transaction)?;
+        }
+
+        // Both matl files define the label, so the first file in the modl should be used.
+        for (id, file_name) in [(0, "alt.numatb"), (1, "model.numatb")] {
+            MatlRecord {
+                id,
+                directory: "model/body/c00".to_string(),
+                file_name: file_name.to_string(),
+                ..Default::default()
+            }
+            .insert(&mut transaction)?;
+            MaterialRecord {
+                id,
+                matl_id: id,
+                material_label: "body".to_string(),
+                ..Default::default()
+            }
+            .insert(&mut transaction)?;
+        }
+        for (id, file_index, file_name) in [(0, 1, "alt.numatb"), (1, 0, "model.numatb")] {
+            ModlMaterialFileRecord {
+                id,
+                modl_id: 0,
+                file_index,
+                file_name: file_name.to_string(),
+            }
+            .insert(&mut transaction)?;
+        }
+
+        ModlEntryRecord {
+            id: 0,
+            modl_id: 0,
+            mesh_object_name: "body".to_string(),
+            mesh_object_sub_index: 1,
+            material_label: "body".to_string(),
+            ..Default::default()
+        }
+        .insert(&mut transaction)?;
+        transaction.commit()?;
+
+        link_modl_entries(&mut connection)?;
+
+        let entries = ModlEntryRecord::select_all(&connection)?;
+        assert_eq!(1, entries.len());
+        assert_eq!(Some(2), entries[0].mesh_object_id);
+        assert_eq!(Some(1), entries[0].material_id);
+        Ok(())
+    }
+
+    #[test]
+    fn process_adj_index_counts() -> Result<()> {
+        use ssbh_lib::formats::adj::{Adj, MeshItem};
+
+        // The offsets are unsorted with a duplicate offset and an offset past the buffer.
+        let adj = Adj {
+            count: 4,
+            items: vec![
+                MeshItem {
+                    mesh_index: 0,
+                    buffer_offset: 8,
+                },
+                MeshItem {
+                    mesh_index: 1,
+                    buffer_offset: 0,
+                },
+                MeshItem {
+                    mesh_index: 2,
+                    buffer_offset: 8,
+                },
+                MeshItem {
+                    mesh_index: 3,
+                    buffer_offset: 40,
+                },
+            ],
+            buffer: vec![0; 10],
+        };
+
+        let mut connection = Connection::open_in_memory()?;
+        let mut transaction = connection.transaction()?;
+        create_tables(&mut transaction)?;
+        for record in process_adj(&adj, "model.adjb", String::new()) {
+            record.insert(&mut transaction)?;
+        }
+
+        let entries: Vec<_> = AdjEntryRecord::select_all(&transaction)?
+            .iter()
+            .map(|e| (e.mesh_object_index, e.index_buffer_offset, e.index_count))
+            .collect();
+        assert_eq!(vec![(1, 0, 4), (0, 8, 0), (2, 8, 6)], entries);
+        Ok(())
+    }
+
+    #[test]
+    fn custom_params_below_max_param_id() {
+        // New ParamId variants past the probed range would be missing from CustomParam.
+        // Check that the largest known ID leaves room for new variants.
+        let max_id = custom_params().iter().map(|(id, _)| *id).max().unwrap();
+        assert!(max_id < MAX_PARAM_ID / 2, "0x{:X}", max_id);
+    }
+
+    #[test]
+    fn insert_unknown_nufx_params() -> Result<()> {
+        let mut connection = Connection::open_in_memory()?;
+        initialize_database(&mut connection)?;
+
+        // Nufx param IDs aren't checked against ParamId when parsing.
+        let mut transaction = connection.transaction()?;
+        transaction.execute_batch("PRAGMA defer_foreign_keys = ON;")?;
+        NufxRecord::default().insert(&mut transaction)?;
+        ShaderProgramRecord::default().insert(&mut transaction)?;
+        for (id, param_id) in [(0, 0), (1, 0x1_0000_0000), (2, 0x1_0000_0000)] {
+            ShaderProgramMaterialParamRecord {
+                id,
+                param_id,
+                ..Default::default()
+            }
+            .insert(&mut transaction)?;
+        }
+        let unknown_params = insert_unknown_params(&mut transaction)?;
+        transaction.commit()?;
+
+        assert_eq!(
+            vec![(0x1_0000_0000, 2)],
+            unknown_params.into_iter().collect::<Vec<_>>()
+        );
+
+        let param = CustomParamRecord::select_where(&connection, "Id", 0x1_0000_0000i64)?;
+        assert_eq!(1, param.len());
+        assert_eq!("Unknown0x100000000", param[0].name);
+        assert!(!param[0].is_known);
+        Ok(())
+    }
+}
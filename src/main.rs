@@ -27,10 +27,18 @@ fn main() {
                 .required(true)
                 .takes_value(true),
         )
+        .arg(
+            Arg::with_name("vertex_stats")
+                .long("vertex-stats")
+                .help("Calculate the min, max, and mean of each vertex attribute")
+                .required(false)
+                .takes_value(false),
+        )
         .get_matches();
 
     let source_folder = Path::new(matches.value_of("input").unwrap());
     let database_path = Path::new(matches.value_of("output").unwrap());
+    let vertex_stats = matches.is_present("vertex_stats");
 
     // Overwrite the file if it already exists.
     if database_path.exists() {
@@ -38,7 +46,7 @@ fn main() {
     }
 
     let duration = Instant::now();
-    match create_database(source_folder, database_path) {
+    match create_database(source_folder, database_path, vertex_stats) {
         Ok(_) => {}
         Err(e) => println!("Error encountered while creating database: {:?}", e),
     }
@@ -1,12 +1,16 @@
-use rusqlite::Transaction;
 use rusqlite::{params, Result};
-use ssbh_lib::formats::matl::{MatlBlendStateV16, MatlRasterizerStateV16, MatlSampler};
+use rusqlite::{Connection, Row, ToSql, Transaction};
+use ssbh_lib::formats::matl::{
+    MatlBlendStateV15, MatlBlendStateV16, MatlRasterizerStateV15, MatlRasterizerStateV16,
+    MatlSampler, MatlUvTransform,
+};
+use ssbh_lib::Matrix4x4;
 use std::fmt::Debug;
 use std::sync::atomic::AtomicUsize;
 use std::sync::atomic::Ordering;
 
 extern crate sqlinsert_derive;
-use sqlinsert_derive::SqlInsert;
+use sqlinsert_derive::{SqlInsert, SqlSelect};
 
 // Simulate an autoincrementing primary key.
 // Use atomics so no two records receive the same key.
@@ -17,15 +21,57 @@ static LAST_TEXTURE_ID: AtomicUsize = AtomicUsize::new(0);
 static LAST_SAMPLER_ID: AtomicUsize = AtomicUsize::new(0);
 static LAST_RASTERIZER_ID: AtomicUsize = AtomicUsize::new(0);
 static LAST_BLEND_STATE_ID: AtomicUsize = AtomicUsize::new(0);
+static LAST_RASTERIZER_V15_ID: AtomicUsize = AtomicUsize::new(0);
+static LAST_BLEND_STATE_V15_ID: AtomicUsize = AtomicUsize::new(0);
+static LAST_UV_TRANSFORM_ID: AtomicUsize = AtomicUsize::new(0);
+static LAST_COLOR4F_ID: AtomicUsize = AtomicUsize::new(0);
+static LAST_PARAM_PARSE_FAILURE_ID: AtomicUsize = AtomicUsize::new(0);
 
 static LAST_MATL_ID: AtomicUsize = AtomicUsize::new(0);
 static LAST_MATERIAL_ID: AtomicUsize = AtomicUsize::new(0);
 
 static LAST_MODL_ID: AtomicUsize = AtomicUsize::new(0);
+static LAST_MODL_ENTRY_ID: AtomicUsize = AtomicUsize::new(0);
+static LAST_MODL_MATERIAL_FILE_ID: AtomicUsize = AtomicUsize::new(0);
 
 static LAST_MESH_ID: AtomicUsize = AtomicUsize::new(0);
 static LAST_MESH_OBJECT_ID: AtomicUsize = AtomicUsize::new(0);
 static LAST_MESH_ATTRIBUTE_ID: AtomicUsize = AtomicUsize::new(0);
+static LAST_MESH_ATTRIBUTE_NAME_ID: AtomicUsize = AtomicUsize::new(0);
+static LAST_MESH_RIGGING_GROUP_ID: AtomicUsize = AtomicUsize::new(0);
+static LAST_VERTEX_ATTRIBUTE_STATS_ID: AtomicUsize = AtomicUsize::new(0);
+
+static LAST_SKEL_ID: AtomicUsize = AtomicUsize::new(0);
+static LAST_BONE_ID: AtomicUsize = AtomicUsize::new(0);
+
+static LAST_ANIM_ID: AtomicUsize = AtomicUsize::new(0);
+static LAST_ANIM_GROUP_ID: AtomicUsize = AtomicUsize::new(0);
+static LAST_ANIM_NODE_ID: AtomicUsize = AtomicUsize::new(0);
+static LAST_ANIM_TRACK_ID: AtomicUsize = AtomicUsize::new(0);
+static LAST_ANIM_MATERIAL_VALUE_ID: AtomicUsize = AtomicUsize::new(0);
+
+static LAST_HLPB_ID: AtomicUsize = AtomicUsize::new(0);
+static LAST_HLPB_AIM_CONSTRAINT_ID: AtomicUsize = AtomicUsize::new(0);
+static LAST_HLPB_ORIENT_CONSTRAINT_ID: AtomicUsize = AtomicUsize::new(0);
+
+static LAST_NUFX_ID: AtomicUsize = AtomicUsize::new(0);
+static LAST_SHADER_PROGRAM_ID: AtomicUsize = AtomicUsize::new(0);
+static LAST_SHADER_PROGRAM_VERTEX_ATTRIBUTE_ID: AtomicUsize = AtomicUsize::new(0);
+static LAST_SHADER_PROGRAM_MATERIAL_PARAM_ID: AtomicUsize = AtomicUsize::new(0);
+
+static LAST_ADJ_ID: AtomicUsize = AtomicUsize::new(0);
+static LAST_ADJ_ENTRY_ID: AtomicUsize = AtomicUsize::new(0);
+
+static LAST_NRPD_ID: AtomicUsize = AtomicUsize::new(0);
+static LAST_NRPD_FRAME_BUFFER_ID: AtomicUsize = AtomicUsize::new(0);
+static LAST_NRPD_STATE_CONTAINER_ID: AtomicUsize = AtomicUsize::new(0);
+static LAST_NRPD_SAMPLER_ID: AtomicUsize = AtomicUsize::new(0);
+static LAST_NRPD_DEPTH_STATE_ID: AtomicUsize = AtomicUsize::new(0);
+static LAST_NRPD_BLEND_STATE_ID: AtomicUsize = AtomicUsize::new(0);
+static LAST_NRPD_RASTERIZER_STATE_ID: AtomicUsize = AtomicUsize::new(0);
+
+static LAST_SHDR_ID: AtomicUsize = AtomicUsize::new(0);
+static LAST_SHADER_ID: AtomicUsize = AtomicUsize::new(0);
 
 static LAST_XMB_ID: AtomicUsize = AtomicUsize::new(0);
 static LAST_XMB_ENTRY_ID: AtomicUsize = AtomicUsize::new(0);
@@ -36,13 +82,53 @@ pub trait SqlInsert: Sync + Send + Debug {
     fn insert(&self, transaction: &mut Transaction) -> Result<()>;
 }
 
-#[derive(SqlInsert, Debug)]
+/// A type that can be read back from the rows of its table.
+pub trait SqlSelect: Sized {
+    /// Create a record from a row with the columns in field order.
+    fn from_row(row: &Row) -> Result<Self>;
+
+    /// Select every row in the table.
+    fn select_all(connection: &Connection) -> Result<Vec<Self>>;
+
+    /// Select the rows where `column` is equal to `value` like `("MaterialId", 1)`.
+    /// Returns an error if `column` is not one of the table's columns.
+    fn select_where<T: ToSql>(connection: &Connection, column: &str, value: T)
+        -> Result<Vec<Self>>;
+}
+
+/// The table definition generated from a record's fields and their attributes.
+pub trait SqlSchema {
+    const TABLE_NAME: &'static str;
+
+    /// The CREATE TABLE statement with column types, keys, and NOT NULL constraints.
+    fn create_table() -> &'static str;
+
+    /// The CREATE INDEX statements for foreign keys, columns marked with `#[index]`,
+    /// and composite indexes like `#[index(material_id, param_id)]`.
+    fn create_indexes() -> &'static str;
+}
+
+#[derive(SqlInsert, SqlSelect, Debug, Default)]
+#[table("CustomParam")]
+pub struct CustomParamRecord {
+    #[primary_key]
+    pub id: i64,
+    pub name: String,
+    pub param_type: Option<String>,
+    pub is_known: bool,
+}
+
+#[derive(SqlInsert, SqlSelect, Debug, Default)]
 #[table("CustomBooleanParam")]
+#[index(material_id, param_id)]
 pub struct BoolRecord {
-    id: i64,
-    param_id: u32,
-    material_id: i64,
-    value: bool,
+    #[primary_key]
+    pub id: i64,
+    #[references("CustomParam")]
+    pub param_id: u32,
+    #[references("Material")]
+    pub material_id: i64,
+    pub value: bool,
 }
 
 impl BoolRecord {
@@ -60,11 +146,15 @@ impl BoolRecord {
     }
 }
 
-#[derive(SqlInsert, Debug)]
+#[derive(SqlInsert, SqlSelect, Debug, Default)]
 #[table("CustomFloatParam")]
+#[index(material_id, param_id)]
 pub struct FloatRecord {
+    #[primary_key]
     pub id: i64,
+    #[references("CustomParam")]
     pub param_id: u32,
+    #[references("Material")]
     pub material_id: i64,
     pub value: f64,
 }
@@ -84,18 +174,22 @@ impl FloatRecord {
     }
 }
 
-#[derive(SqlInsert, Debug)]
+#[derive(SqlInsert, SqlSelect, Debug, Default)]
 #[table("RasterizerState")]
+#[index(material_id, param_id)]
 pub struct RasterizerRecord {
-    id: i64,
-    param_id: u32,
-    material_id: i64,
-    fill_mode: u32,
-    cull_mode: u32,
-    depth_bias: f64,
-    unk4: f64,
-    unk5: f64,
-    unk6: u32,
+    #[primary_key]
+    pub id: i64,
+    #[references("CustomParam")]
+    pub param_id: u32,
+    #[references("Material")]
+    pub material_id: i64,
+    pub fill_mode: u32,
+    pub cull_mode: u32,
+    pub depth_bias: f64,
+    pub unk4: f64,
+    pub unk5: f64,
+    pub unk6: u32,
 }
 
 impl RasterizerRecord {
@@ -122,22 +216,60 @@ impl RasterizerRecord {
     }
 }
 
-#[derive(SqlInsert, Debug)]
+#[derive(SqlInsert, SqlSelect, Debug, Default)]
+#[table("RasterizerStateV15")]
+#[index(material_id, param_id)]
+pub struct RasterizerV15Record {
+    #[primary_key]
+    pub id: i64,
+    #[references("CustomParam")]
+    pub param_id: u32,
+    #[references("Material")]
+    pub material_id: i64,
+    pub unk1: u32,
+    pub unk2: u32,
+}
+
+impl RasterizerV15Record {
+    pub fn create_record(
+        param_id: u32,
+        material_id: i64,
+        val: &MatlRasterizerStateV15,
+    ) -> (i64, RasterizerV15Record) {
+        let id = LAST_RASTERIZER_V15_ID.fetch_add(1, Ordering::Relaxed) as i64;
+        (
+            id,
+            RasterizerV15Record {
+                id,
+                param_id,
+                material_id,
+                unk1: val.unk1,
+                unk2: val.unk2,
+            },
+        )
+    }
+}
+
+#[derive(SqlInsert, SqlSelect, Debug, Default)]
 #[table("BlendState")]
+#[index(material_id, param_id)]
 pub struct BlendStateRecord {
-    id: i64,
-    param_id: u32,
-    material_id: i64,
-    source_color: u32,
-    unk2: u32,
-    destination_color: u32,
-    unk4: u32,
-    unk5: u32,
-    unk6: u32,
-    unk7: u32,
-    unk8: u32,
-    unk9: u32,
-    unk10: u32,
+    #[primary_key]
+    pub id: i64,
+    #[references("CustomParam")]
+    pub param_id: u32,
+    #[references("Material")]
+    pub material_id: i64,
+    pub source_color: u32,
+    pub unk2: u32,
+    pub destination_color: u32,
+    pub unk4: u32,
+    pub unk5: u32,
+    pub unk6: u32,
+    pub unk7: u32,
+    pub unk8: u32,
+    pub unk9: u32,
+    pub unk10: u32,
 }
 
 impl BlendStateRecord {
@@ -168,26 +300,78 @@ impl BlendStateRecord {
     }
 }
 
-#[derive(SqlInsert, Debug)]
+#[derive(SqlInsert, SqlSelect, Debug, Default)]
+#[table("BlendStateV15")]
+#[index(material_id, param_id)]
+pub struct BlendStateV15Record {
+    #[primary_key]
+    pub id: i64,
+    #[references("CustomParam")]
+    pub param_id: u32,
+    #[references("Material")]
+    pub material_id: i64,
+    pub unk1: i64,
+    pub unk2: u32,
+    pub unk3: u32,
+    pub unk4: u32,
+    pub unk5: u32,
+    pub unk6: i64,
+    pub unk7: u32,
+    pub unk8: u32,
+    pub unk9: u32,
+}
+
+impl BlendStateV15Record {
+    pub fn create_record(
+        param_id: u32,
+        material_id: i64,
+        val: &MatlBlendStateV15,
+    ) -> (i64, BlendStateV15Record) {
+        let id = LAST_BLEND_STATE_V15_ID.fetch_add(1, Ordering::Relaxed) as i64;
+        (
+            id,
+            BlendStateV15Record {
+                id,
+                param_id,
+                material_id,
+                unk1: val.unk1 as i64,
+                unk2: val.unk2,
+                unk3: val.unk3,
+                unk4: val.unk4,
+                unk5: val.unk5,
+                unk6: val.unk6 as i64,
+                unk7: val.unk7,
+                unk8: val.unk8,
+                unk9: val.unk9,
+            },
+        )
+    }
+}
+
+#[derive(SqlInsert, SqlSelect, Debug, Default)]
 #[table("Sampler")]
+#[index(material_id, param_id)]
 pub struct SamplerRecord {
-    id: i64,
-    param_id: u32,
-    material_id: i64,
-    wraps: u32,
-    wrapt: u32,
-    wrapr: u32,
-    min_filter: u32,
-    mag_filter: u32,
-    texture_filtering_type: u32,
-    border_color_r: f64,
-    border_color_g: f64,
-    border_color_b: f64,
-    border_color_a: f64,
-    unk11: u32,
-    unk12: u32,
-    lod_bias: f64,
-    max_anisotropy: u32,
+    #[primary_key]
+    pub id: i64,
+    #[references("CustomParam")]
+    pub param_id: u32,
+    #[references("Material")]
+    pub material_id: i64,
+    pub wraps: u32,
+    pub wrapt: u32,
+    pub wrapr: u32,
+    pub min_filter: u32,
+    pub mag_filter: u32,
+    pub texture_filtering_type: u32,
+    pub border_color_r: f64,
+    pub border_color_g: f64,
+    pub border_color_b: f64,
+    pub border_color_a: f64,
+    pub unk11: u32,
+    pub unk12: u32,
+    pub lod_bias: f64,
+    pub max_anisotropy: u32,
 }
 
 impl SamplerRecord {
@@ -222,13 +406,125 @@ impl SamplerRecord {
     }
 }
 
-#[derive(SqlInsert, Debug)]
+#[derive(SqlInsert, SqlSelect, Debug, Default)]
+#[table("UvTransform")]
+#[index(material_id, param_id)]
+pub struct UvTransformRecord {
+    #[primary_key]
+    pub id: i64,
+    #[references("CustomParam")]
+    pub param_id: u32,
+    #[references("Material")]
+    pub material_id: i64,
+    pub x: f64,
+    pub y: f64,
+    pub z: f64,
+    pub w: f64,
+    pub v: f64,
+}
+
+impl UvTransformRecord {
+    pub fn create_record(
+        param_id: u32,
+        material_id: i64,
+        val: &MatlUvTransform,
+    ) -> (i64, UvTransformRecord) {
+        let id = LAST_UV_TRANSFORM_ID.fetch_add(1, Ordering::Relaxed) as i64;
+        (
+            id,
+            UvTransformRecord {
+                id,
+                param_id,
+                material_id,
+                x: val.x as f64,
+                y: val.y as f64,
+                z: val.z as f64,
+                w: val.w as f64,
+                v: val.v as f64,
+            },
+        )
+    }
+}
+
+#[derive(SqlInsert, SqlSelect, Debug, Default)]
+#[table("CustomColor4fParam")]
+#[index(material_id, param_id)]
+pub struct Color4fRecord {
+    #[primary_key]
+    pub id: i64,
+    #[references("CustomParam")]
+    pub param_id: u32,
+    #[references("Material")]
+    pub material_id: i64,
+    pub r: f64,
+    pub g: f64,
+    pub b: f64,
+    pub a: f64,
+}
+
+impl Color4fRecord {
+    pub fn create_record(
+        param_id: u32,
+        material_id: i64,
+        val: &ssbh_lib::Color4f,
+    ) -> (i64, Color4fRecord) {
+        let id = LAST_COLOR4F_ID.fetch_add(1, Ordering::Relaxed) as i64;
+        (
+            id,
+            Color4fRecord {
+                id,
+                param_id,
+                material_id,
+                r: val.r as f64,
+                g: val.g as f64,
+                b: val.b as f64,
+                a: val.a as f64,
+            },
+        )
+    }
+}
+
+#[derive(SqlInsert, SqlSelect, Debug, Default)]
+#[table("ParamParseFailure")]
+#[index(material_id, param_id)]
+pub struct ParamParseFailureRecord {
+    #[primary_key]
+    pub id: i64,
+    #[references("CustomParam")]
+    pub param_id: u32,
+    #[references("Material")]
+    pub material_id: i64,
+    pub data_type: u32,
+}
+
+impl ParamParseFailureRecord {
+    pub fn create_record(
+        param_id: u32,
+        material_id: i64,
+        data_type: u32,
+    ) -> (i64, ParamParseFailureRecord) {
+        let id = LAST_PARAM_PARSE_FAILURE_ID.fetch_add(1, Ordering::Relaxed) as i64;
+        (
+            id,
+            ParamParseFailureRecord {
+                id,
+                param_id,
+                material_id,
+                data_type,
+            },
+        )
+    }
+}
+
+#[derive(SqlInsert, SqlSelect, Debug, Default)]
 #[table("Material")]
 pub struct MaterialRecord {
-    id: i64,
-    matl_id: i64,
-    material_label: String,
-    shader_label: String,
+    #[primary_key]
+    pub id: i64,
+    #[references("Matl")]
+    pub matl_id: i64,
+    pub material_label: String,
+    pub shader_label: String,
 }
 
 impl MaterialRecord {
@@ -250,13 +546,17 @@ impl MaterialRecord {
     }
 }
 
-#[derive(SqlInsert, Debug)]
+#[derive(SqlInsert, SqlSelect, Debug, Default)]
 #[table("Texture")]
+#[index(material_id, param_id)]
 pub struct TextureRecord {
-    id: i64,
-    param_id: u32,
-    material_id: i64,
-    value: String,
+    #[primary_key]
+    pub id: i64,
+    #[references("CustomParam")]
+    pub param_id: u32,
+    #[references("Material")]
+    pub material_id: i64,
+    pub value: Option<String>,
 }
 
 impl TextureRecord {
@@ -268,22 +568,26 @@ impl TextureRecord {
                 id,
                 param_id,
                 material_id,
-                value: text,
+                value: Some(text),
             },
         )
     }
 }
 
-#[derive(SqlInsert, Debug)]
+#[derive(SqlInsert, SqlSelect, Debug, Default)]
 #[table("CustomVectorParam")]
+#[index(material_id, param_id)]
 pub struct Vector4Record {
-    id: i64,
-    param_id: u32,
-    material_id: i64,
-    x: f64,
-    y: f64,
-    z: f64,
-    w: f64,
+    #[primary_key]
+    pub id: i64,
+    #[references("CustomParam")]
+    pub param_id: u32,
+    #[references("Material")]
+    pub material_id: i64,
+    pub x: f64,
+    pub y: f64,
+    pub z: f64,
+    pub w: f64,
 }
 
 impl Vector4Record {
@@ -311,34 +615,43 @@ impl Vector4Record {
     }
 }
 
-#[derive(SqlInsert, Debug)]
+#[derive(SqlInsert, SqlSelect, Debug, Default)]
 #[table("Matl")]
 pub struct MatlRecord {
-    id: i64,
-    directory: String,
-    file_name: String,
+    #[primary_key]
+    pub id: i64,
+    #[index]
+    pub directory: String,
+    pub file_name: String,
+    pub version: String,
 }
 
 impl MatlRecord {
-    pub fn create_record(directory: String, file_name: String) -> (i64, MatlRecord) {
+    pub fn create_record(
+        directory: String,
+        file_name: String,
+        version: String,
+    ) -> (i64, MatlRecord) {
         let id = LAST_MATL_ID.fetch_add(1, Ordering::Relaxed) as i64;
         (
-            id as i64,
+            id,
             MatlRecord {
                 id,
                 directory,
                 file_name,
+                version,
             },
         )
     }
 }
 
-#[derive(SqlInsert, Debug)]
+#[derive(SqlInsert, SqlSelect, Debug, Default)]
 #[table("Xmb")]
 pub struct XmbRecord {
-    id: i64,
-    directory: String,
-    file_name: String,
+    #[primary_key]
+    pub id: i64,
+    pub directory: String,
+    pub file_name: String,
 }
 
 impl XmbRecord {
@@ -355,12 +668,14 @@ impl XmbRecord {
     }
 }
 
-#[derive(SqlInsert, Debug)]
+#[derive(SqlInsert, SqlSelect, Debug, Default)]
 #[table("XmbEntry")]
 pub struct XmbEntryRecord {
-    id: i64,
-    xmb_id: i64,
-    name: String,
+    #[primary_key]
+    pub id: i64,
+    #[references("Xmb")]
+    pub xmb_id: i64,
+    pub name: String,
 }
 
 impl XmbEntryRecord {
@@ -370,13 +685,15 @@ impl XmbEntryRecord {
     }
 }
 
-#[derive(SqlInsert, Debug)]
+#[derive(SqlInsert, SqlSelect, Debug, Default)]
 #[table("XmbAttribute")]
 pub struct XmbAttributeRecord {
-    id: i64,
-    xmb_entry_id: i64,
-    name: String,
-    value: String,
+    #[primary_key]
+    pub id: i64,
+    #[references("XmbEntry")]
+    pub xmb_entry_id: i64,
+    pub name: String,
+    pub value: String,
 }
 
 impl XmbAttributeRecord {
@@ -387,7 +704,7 @@ impl XmbAttributeRecord {
     ) -> (i64, XmbAttributeRecord) {
         let id = LAST_XMB_ATTRIBUTE_ID.fetch_add(1, Ordering::Relaxed) as i64;
         (
-            id as i64,
+            id,
             XmbAttributeRecord {
                 id,
                 xmb_entry_id,
@@ -398,16 +715,23 @@ impl XmbAttributeRecord {
     }
 }
 
-#[derive(SqlInsert, Debug)]
+#[derive(SqlInsert, SqlSelect, Debug, Default)]
 #[table("Mesh")]
 pub struct MeshRecord {
-    id: i64,
-    directory: String,
-    file_name: String,
+    #[primary_key]
+    pub id: i64,
+    #[index]
+    pub directory: String,
+    pub file_name: String,
+    pub version: String,
 }
 
 impl MeshRecord {
-    pub fn create_record(directory: String, file_name: String) -> (i64, MeshRecord) {
+    pub fn create_record(
+        directory: String,
+        file_name: String,
+        version: String,
+    ) -> (i64, MeshRecord) {
         let id = LAST_MESH_ID.fetch_add(1, Ordering::Relaxed) as i64;
         (
             id,
@@ -415,29 +739,35 @@ impl MeshRecord {
                 id,
                 directory,
                 file_name,
+                version,
             },
         )
     }
 }
 
-#[derive(SqlInsert, Debug)]
+#[derive(SqlInsert, SqlSelect, Debug, Default)]
 #[table("Modl")]
 pub struct ModlRecord {
-    id: i64,
-    directory: String,
-    file_name: String,
-    model_file_name: String,
-    skeleton_file_name: String,
-    material_file_name: String,
+    #[primary_key]
+    pub id: i64,
+    pub directory: String,
+    pub file_name: String,
+    pub model_file_name: String,
+    pub skeleton_file_name: String,
+    pub material_file_name: Option<String>,
+    pub mesh_file_name: String,
 }
 
 impl ModlRecord {
+    /// The `material_file_name` is the first material file or `None` if there are no material files.
+    /// See `ModlMaterialFileRecord` for all the material files.
     pub fn create_record(
         directory: String,
         file_name: String,
         model_file_name: String,
         skeleton_file_name: String,
-        material_file_name: String,
+        material_file_name: Option<String>,
+        mesh_file_name: String,
     ) -> (i64, ModlRecord) {
         let id = LAST_MODL_ID.fetch_add(1, Ordering::Relaxed) as i64;
         (
@@ -449,59 +779,1312 @@ impl ModlRecord {
                 model_file_name,
                 skeleton_file_name,
                 material_file_name,
+                mesh_file_name,
+            },
+        )
+    }
+}
+
+#[derive(SqlInsert, SqlSelect, Debug, Default)]
+#[table("ModlMaterialFile")]
+pub struct ModlMaterialFileRecord {
+    #[primary_key]
+    pub id: i64,
+    #[references("Modl")]
+    pub modl_id: i64,
+    pub file_index: i64,
+    pub file_name: String,
+}
+
+impl ModlMaterialFileRecord {
+    pub fn create_record(
+        modl_id: i64,
+        file_index: i64,
+        file_name: String,
+    ) -> (i64, ModlMaterialFileRecord) {
+        let id = LAST_MODL_MATERIAL_FILE_ID.fetch_add(1, Ordering::Relaxed) as i64;
+        (
+            id,
+            ModlMaterialFileRecord {
+                id,
+                modl_id,
+                file_index,
+                file_name,
+            },
+        )
+    }
+}
+
+#[derive(SqlInsert, SqlSelect, Debug, Default)]
+#[table("ModlEntry")]
+pub struct ModlEntryRecord {
+    #[primary_key]
+    pub id: i64,
+    #[references("Modl")]
+    pub modl_id: i64,
+    pub mesh_object_name: String,
+    pub mesh_object_sub_index: i64,
+    pub material_label: String,
+    // Linked to the mesh and matl in the same directory after all files are processed.
+    #[references("MeshObject")]
+    pub mesh_object_id: Option<i64>,
+    #[references("Material")]
+    pub material_id: Option<i64>,
+}
+
+impl ModlEntryRecord {
+    pub fn create_record(
+        modl_id: i64,
+        mesh_object_name: String,
+        mesh_object_sub_index: i64,
+        material_label: String,
+    ) -> (i64, ModlEntryRecord) {
+        let id = LAST_MODL_ENTRY_ID.fetch_add(1, Ordering::Relaxed) as i64;
+        (
+            id,
+            ModlEntryRecord {
+                id,
+                modl_id,
+                mesh_object_name,
+                mesh_object_sub_index,
+                material_label,
+                mesh_object_id: None,
+                material_id: None,
             },
         )
     }
 }
 
-#[derive(SqlInsert, Debug)]
+#[derive(SqlInsert, SqlSelect, Debug, Default)]
 #[table("MeshObject")]
+#[index(mesh_id, mesh_object_index)]
 pub struct MeshObjectRecord {
-    id: i64,
-    mesh_id: i64,
-    name: String,
-    sub_index: i64,
+    #[primary_key]
+    pub id: i64,
+    #[references("Mesh")]
+    pub mesh_id: i64,
+    pub mesh_object_index: i64,
+    pub name: String,
+    pub sub_index: i64,
+    pub parent_bone_name: String,
+    pub vertex_count: u32,
+    pub vertex_index_count: u32,
+    pub draw_element_type: u32,
+    pub use_vertex_skinning: u32,
+    pub max_influences: Option<u32>,
+    pub rigging_flags_unk1: Option<u32>,
+    pub bounding_sphere_x: f64,
+    pub bounding_sphere_y: f64,
+    pub bounding_sphere_z: f64,
+    pub bounding_sphere_radius: f64,
+    pub bounding_box_min_x: f64,
+    pub bounding_box_min_y: f64,
+    pub bounding_box_min_z: f64,
+    pub bounding_box_max_x: f64,
+    pub bounding_box_max_y: f64,
+    pub bounding_box_max_z: f64,
+    pub oriented_bounding_box_center_x: f64,
+    pub oriented_bounding_box_center_y: f64,
+    pub oriented_bounding_box_center_z: f64,
+    pub oriented_bounding_box_transform_m11: f64,
+    pub oriented_bounding_box_transform_m12: f64,
+    pub oriented_bounding_box_transform_m13: f64,
+    pub oriented_bounding_box_transform_m21: f64,
+    pub oriented_bounding_box_transform_m22: f64,
+    pub oriented_bounding_box_transform_m23: f64,
+    pub oriented_bounding_box_transform_m31: f64,
+    pub oriented_bounding_box_transform_m32: f64,
+    pub oriented_bounding_box_transform_m33: f64,
+    pub oriented_bounding_box_size_x: f64,
+    pub oriented_bounding_box_size_y: f64,
+    pub oriented_bounding_box_size_z: f64,
 }
 
 impl MeshObjectRecord {
     pub fn create_record(
         mesh_id: i64,
-        mesh_name: String,
-        sub_index: i64,
+        mesh_object_index: i64,
+        object: &ssbh_lib::formats::mesh::MeshObject,
+        rigging_flags: Option<&ssbh_lib::formats::mesh::RiggingFlags>,
     ) -> (i64, MeshObjectRecord) {
         let id = LAST_MESH_OBJECT_ID.fetch_add(1, Ordering::Relaxed) as i64;
+        let sphere = &object.bounding_info.bounding_sphere;
+        let bounding_box = &object.bounding_info.bounding_volume;
+        let oriented_box = &object.bounding_info.oriented_bounding_box;
         (
-            id as i64,
+            id,
             MeshObjectRecord {
                 id,
                 mesh_id,
-                name: mesh_name,
-                sub_index,
+                mesh_object_index,
+                name: object.name.to_string_lossy(),
+                sub_index: object.sub_index as i64,
+                parent_bone_name: object.parent_bone_name.to_string_lossy(),
+                vertex_count: object.vertex_count,
+                vertex_index_count: object.vertex_index_count,
+                draw_element_type: object.draw_element_type as u32,
+                use_vertex_skinning: object.use_vertex_skinning,
+                max_influences: rigging_flags.map(|f| f.max_influences as u32),
+                rigging_flags_unk1: rigging_flags.map(|f| f.unk1 as u32),
+                bounding_sphere_x: sphere.center.x as f64,
+                bounding_sphere_y: sphere.center.y as f64,
+                bounding_sphere_z: sphere.center.z as f64,
+                bounding_sphere_radius: sphere.radius as f64,
+                bounding_box_min_x: bounding_box.min.x as f64,
+                bounding_box_min_y: bounding_box.min.y as f64,
+                bounding_box_min_z: bounding_box.min.z as f64,
+                bounding_box_max_x: bounding_box.max.x as f64,
+                bounding_box_max_y: bounding_box.max.y as f64,
+                bounding_box_max_z: bounding_box.max.z as f64,
+                oriented_bounding_box_center_x: oriented_box.center.x as f64,
+                oriented_bounding_box_center_y: oriented_box.center.y as f64,
+                oriented_bounding_box_center_z: oriented_box.center.z as f64,
+                oriented_bounding_box_transform_m11: oriented_box.transform.row1.x as f64,
+                oriented_bounding_box_transform_m12: oriented_box.transform.row1.y as f64,
+                oriented_bounding_box_transform_m13: oriented_box.transform.row1.z as f64,
+                oriented_bounding_box_transform_m21: oriented_box.transform.row2.x as f64,
+                oriented_bounding_box_transform_m22: oriented_box.transform.row2.y as f64,
+                oriented_bounding_box_transform_m23: oriented_box.transform.row2.z as f64,
+                oriented_bounding_box_transform_m31: oriented_box.transform.row3.x as f64,
+                oriented_bounding_box_transform_m32: oriented_box.transform.row3.y as f64,
+                oriented_bounding_box_transform_m33: oriented_box.transform.row3.z as f64,
+                oriented_bounding_box_size_x: oriented_box.size.x as f64,
+                oriented_bounding_box_size_y: oriented_box.size.y as f64,
+                oriented_bounding_box_size_z: oriented_box.size.z as f64,
             },
         )
     }
 }
 
-#[derive(SqlInsert, Debug)]
+#[derive(SqlInsert, SqlSelect, Debug, Default)]
 #[table("MeshAttribute")]
 pub struct MeshAttributeRecord {
-    id: i64,
-    mesh_object_id: i64,
-    name: String,
+    #[primary_key]
+    pub id: i64,
+    #[references("MeshObject")]
+    pub mesh_object_id: i64,
+    pub name: String,
+    pub usage: String,
+    pub data_type: String,
+    pub buffer_index: u32,
+    pub buffer_offset: u32,
+    pub sub_index: i64,
 }
 
 impl MeshAttributeRecord {
     pub fn create_record(
         mesh_object_id: i64,
         attribute_name: String,
+        usage: String,
+        data_type: String,
+        buffer_index: u32,
+        buffer_offset: u32,
+        sub_index: i64,
     ) -> (i64, MeshAttributeRecord) {
         let id = LAST_MESH_ATTRIBUTE_ID.fetch_add(1, Ordering::Relaxed) as i64;
         (
-            id as i64,
+            id,
             MeshAttributeRecord {
                 id,
                 mesh_object_id,
                 name: attribute_name,
+                usage,
+                data_type,
+                buffer_index,
+                buffer_offset,
+                sub_index,
+            },
+        )
+    }
+}
+
+#[derive(SqlInsert, SqlSelect, Debug, Default)]
+#[table("MeshAttributeName")]
+pub struct MeshAttributeNameRecord {
+    #[primary_key]
+    pub id: i64,
+    #[references("MeshAttribute")]
+    pub mesh_attribute_id: i64,
+    pub name_index: i64,
+    pub name: String,
+}
+
+impl MeshAttributeNameRecord {
+    pub fn create_record(
+        mesh_attribute_id: i64,
+        name_index: i64,
+        name: String,
+    ) -> (i64, MeshAttributeNameRecord) {
+        let id = LAST_MESH_ATTRIBUTE_NAME_ID.fetch_add(1, Ordering::Relaxed) as i64;
+        (
+            id,
+            MeshAttributeNameRecord {
+                id,
+                mesh_attribute_id,
+                name_index,
+                name,
+            },
+        )
+    }
+}
+
+#[derive(SqlInsert, SqlSelect, Debug, Default)]
+#[table("MeshRiggingGroup")]
+pub struct MeshRiggingGroupRecord {
+    #[primary_key]
+    pub id: i64,
+    #[references("MeshObject")]
+    pub mesh_object_id: i64,
+    #[index]
+    pub bone_name: String,
+    pub influence_count: i64,
+}
+
+impl MeshRiggingGroupRecord {
+    pub fn create_record(
+        mesh_object_id: i64,
+        bone_name: String,
+        influence_count: i64,
+    ) -> (i64, MeshRiggingGroupRecord) {
+        let id = LAST_MESH_RIGGING_GROUP_ID.fetch_add(1, Ordering::Relaxed) as i64;
+        (
+            id,
+            MeshRiggingGroupRecord {
+                id,
+                mesh_object_id,
+                bone_name,
+                influence_count,
+            },
+        )
+    }
+}
+
+#[derive(SqlInsert, SqlSelect, Debug, Default)]
+#[table("VertexAttributeStats")]
+pub struct VertexAttributeStatsRecord {
+    #[primary_key]
+    pub id: i64,
+    #[references("MeshObject")]
+    pub mesh_object_id: i64,
+    #[references("MeshAttribute")]
+    pub mesh_attribute_id: Option<i64>,
+    pub attribute_name: String,
+    pub component: i64,
+    pub min: f64,
+    pub max: f64,
+    pub mean: f64,
+}
+
+impl VertexAttributeStatsRecord {
+    pub fn create_record(
+        mesh_object_id: i64,
+        mesh_attribute_id: Option<i64>,
+        attribute_name: String,
+        component: i64,
+        min: f64,
+        max: f64,
+        mean: f64,
+    ) -> (i64, VertexAttributeStatsRecord) {
+        let id = LAST_VERTEX_ATTRIBUTE_STATS_ID.fetch_add(1, Ordering::Relaxed) as i64;
+        (
+            id,
+            VertexAttributeStatsRecord {
+                id,
+                mesh_object_id,
+                mesh_attribute_id,
+                attribute_name,
+                component,
+                min,
+                max,
+                mean,
+            },
+        )
+    }
+}
+
+#[derive(SqlInsert, SqlSelect, Debug, Default)]
+#[table("Skel")]
+pub struct SkelRecord {
+    #[primary_key]
+    pub id: i64,
+    pub directory: String,
+    pub file_name: String,
+}
+
+impl SkelRecord {
+    pub fn create_record(directory: String, file_name: String) -> (i64, SkelRecord) {
+        let id = LAST_SKEL_ID.fetch_add(1, Ordering::Relaxed) as i64;
+        (
+            id,
+            SkelRecord {
+                id,
+                directory,
+                file_name,
+            },
+        )
+    }
+}
+
+#[derive(SqlInsert, SqlSelect, Debug, Default)]
+#[table("Bone")]
+pub struct BoneRecord {
+    #[primary_key]
+    pub id: i64,
+    #[references("Skel")]
+    pub skel_id: i64,
+    pub name: String,
+    pub bone_index: i64,
+    pub parent_index: i64,
+    pub flags_unk1: u32,
+    pub billboard_type: u32,
+    pub transform_m11: f64,
+    pub transform_m12: f64,
+    pub transform_m13: f64,
+    pub transform_m14: f64,
+    pub transform_m21: f64,
+    pub transform_m22: f64,
+    pub transform_m23: f64,
+    pub transform_m24: f64,
+    pub transform_m31: f64,
+    pub transform_m32: f64,
+    pub transform_m33: f64,
+    pub transform_m34: f64,
+    pub transform_m41: f64,
+    pub transform_m42: f64,
+    pub transform_m43: f64,
+    pub transform_m44: f64,
+    pub world_transform_m11: f64,
+    pub world_transform_m12: f64,
+    pub world_transform_m13: f64,
+    pub world_transform_m14: f64,
+    pub world_transform_m21: f64,
+    pub world_transform_m22: f64,
+    pub world_transform_m23: f64,
+    pub world_transform_m24: f64,
+    pub world_transform_m31: f64,
+    pub world_transform_m32: f64,
+    pub world_transform_m33: f64,
+    pub world_transform_m34: f64,
+    pub world_transform_m41: f64,
+    pub world_transform_m42: f64,
+    pub world_transform_m43: f64,
+    pub world_transform_m44: f64,
+}
+
+impl BoneRecord {
+    pub fn create_record(
+        skel_id: i64,
+        bone: &ssbh_lib::formats::skel::SkelBoneEntry,
+        transform: &Matrix4x4,
+        world_transform: &Matrix4x4,
+    ) -> (i64, BoneRecord) {
+        let id = LAST_BONE_ID.fetch_add(1, Ordering::Relaxed) as i64;
+        (
+            id,
+            BoneRecord {
+                id,
+                skel_id,
+                name: bone.name.to_string_lossy(),
+                bone_index: bone.index as i64,
+                parent_index: bone.parent_index as i64,
+                flags_unk1: bone.flags.unk1 as u32,
+                billboard_type: bone.flags.billboard_type as u32,
+                transform_m11: transform.row1.x as f64,
+                transform_m12: transform.row1.y as f64,
+                transform_m13: transform.row1.z as f64,
+                transform_m14: transform.row1.w as f64,
+                transform_m21: transform.row2.x as f64,
+                transform_m22: transform.row2.y as f64,
+                transform_m23: transform.row2.z as f64,
+                transform_m24: transform.row2.w as f64,
+                transform_m31: transform.row3.x as f64,
+                transform_m32: transform.row3.y as f64,
+                transform_m33: transform.row3.z as f64,
+                transform_m34: transform.row3.w as f64,
+                transform_m41: transform.row4.x as f64,
+                transform_m42: transform.row4.y as f64,
+                transform_m43: transform.row4.z as f64,
+                transform_m44: transform.row4.w as f64,
+                world_transform_m11: world_transform.row1.x as f64,
+                world_transform_m12: world_transform.row1.y as f64,
+                world_transform_m13: world_transform.row1.z as f64,
+                world_transform_m14: world_transform.row1.w as f64,
+                world_transform_m21: world_transform.row2.x as f64,
+                world_transform_m22: world_transform.row2.y as f64,
+                world_transform_m23: world_transform.row2.z as f64,
+                world_transform_m24: world_transform.row2.w as f64,
+                world_transform_m31: world_transform.row3.x as f64,
+                world_transform_m32: world_transform.row3.y as f64,
+                world_transform_m33: world_transform.row3.z as f64,
+                world_transform_m34: world_transform.row3.w as f64,
+                world_transform_m41: world_transform.row4.x as f64,
+                world_transform_m42: world_transform.row4.y as f64,
+                world_transform_m43: world_transform.row4.z as f64,
+                world_transform_m44: world_transform.row4.w as f64,
+            },
+        )
+    }
+}
+
+#[derive(SqlInsert, SqlSelect, Debug, Default)]
+#[table("Anim")]
+pub struct AnimRecord {
+    #[primary_key]
+    pub id: i64,
+    pub directory: String,
+    pub file_name: String,
+    pub name: String,
+    pub final_frame_index: f64,
+}
+
+impl AnimRecord {
+    pub fn create_record(
+        directory: String,
+        file_name: String,
+        name: String,
+        final_frame_index: f64,
+    ) -> (i64, AnimRecord) {
+        let id = LAST_ANIM_ID.fetch_add(1, Ordering::Relaxed) as i64;
+        (
+            id,
+            AnimRecord {
+                id,
+                directory,
+                file_name,
+                name,
+                final_frame_index,
+            },
+        )
+    }
+}
+
+#[derive(SqlInsert, SqlSelect, Debug, Default)]
+#[table("AnimGroup")]
+pub struct AnimGroupRecord {
+    #[primary_key]
+    pub id: i64,
+    #[references("Anim")]
+    pub anim_id: i64,
+    pub group_type: u32,
+}
+
+impl AnimGroupRecord {
+    pub fn create_record(anim_id: i64, group_type: u32) -> (i64, AnimGroupRecord) {
+        let id = LAST_ANIM_GROUP_ID.fetch_add(1, Ordering::Relaxed) as i64;
+        (
+            id,
+            AnimGroupRecord {
+                id,
+                anim_id,
+                group_type,
+            },
+        )
+    }
+}
+
+#[derive(SqlInsert, SqlSelect, Debug, Default)]
+#[table("AnimNode")]
+pub struct AnimNodeRecord {
+    #[primary_key]
+    pub id: i64,
+    #[references("AnimGroup")]
+    pub anim_group_id: i64,
+    pub name: String,
+}
+
+impl AnimNodeRecord {
+    pub fn create_record(anim_group_id: i64, name: String) -> (i64, AnimNodeRecord) {
+        let id = LAST_ANIM_NODE_ID.fetch_add(1, Ordering::Relaxed) as i64;
+        (
+            id,
+            AnimNodeRecord {
+                id,
+                anim_group_id,
+                name,
+            },
+        )
+    }
+}
+
+#[derive(SqlInsert, SqlSelect, Debug, Default)]
+#[table("AnimTrack")]
+pub struct AnimTrackRecord {
+    #[primary_key]
+    pub id: i64,
+    #[references("AnimNode")]
+    pub anim_node_id: i64,
+    #[index]
+    pub name: String,
+    pub track_type: u32,
+    pub compression_type: u32,
+    pub frame_count: u32,
+    pub data_offset: u32,
+    pub data_size: i64,
+}
+
+impl AnimTrackRecord {
+    pub fn create_record(
+        anim_node_id: i64,
+        track: &ssbh_lib::formats::anim::AnimTrackV2,
+    ) -> (i64, AnimTrackRecord) {
+        let id = LAST_ANIM_TRACK_ID.fetch_add(1, Ordering::Relaxed) as i64;
+        (
+            id,
+            AnimTrackRecord {
+                id,
+                anim_node_id,
+                name: track.name.to_string_lossy(),
+                track_type: track.flags.track_type as u32,
+                compression_type: track.flags.compression_type as u32,
+                frame_count: track.frame_count,
+                data_offset: track.data_offset,
+                data_size: track.data_size as i64,
+            },
+        )
+    }
+}
+
+#[derive(SqlInsert, SqlSelect, Debug, Default)]
+#[table("AnimMaterialValue")]
+pub struct AnimMaterialValueRecord {
+    #[primary_key]
+    pub id: i64,
+    #[references("Anim")]
+    pub anim_id: i64,
+    #[index]
+    pub material_name: String,
+    pub param_name: String,
+    pub frame: i64,
+    pub value_type: String,
+    pub x: f64,
+    pub y: Option<f64>,
+    pub z: Option<f64>,
+    pub w: Option<f64>,
+    pub v: Option<f64>,
+}
+
+impl AnimMaterialValueRecord {
+    /// Scalar values only use the first component, so the remaining components are stored as NULL.
+    /// UvTransform values store scale u, scale v, rotation, translate u, and translate v in x, y, z, w, and v.
+    pub fn create_record(
+        anim_id: i64,
+        material_name: String,
+        param_name: String,
+        frame: i64,
+        value_type: &str,
+        value: &[f64],
+    ) -> (i64, AnimMaterialValueRecord) {
+        let id = LAST_ANIM_MATERIAL_VALUE_ID.fetch_add(1, Ordering::Relaxed) as i64;
+        (
+            id,
+            AnimMaterialValueRecord {
+                id,
+                anim_id,
+                material_name,
+                param_name,
+                frame,
+                value_type: value_type.to_string(),
+                x: value[0],
+                y: value.get(1).copied(),
+                z: value.get(2).copied(),
+                w: value.get(3).copied(),
+                v: value.get(4).copied(),
+            },
+        )
+    }
+}
+
+#[derive(SqlInsert, SqlSelect, Debug, Default)]
+#[table("Hlpb")]
+pub struct HlpbRecord {
+    #[primary_key]
+    pub id: i64,
+    pub directory: String,
+    pub file_name: String,
+}
+
+impl HlpbRecord {
+    pub fn create_record(directory: String, file_name: String) -> (i64, HlpbRecord) {
+        let id = LAST_HLPB_ID.fetch_add(1, Ordering::Relaxed) as i64;
+        (
+            id,
+            HlpbRecord {
+                id,
+                directory,
+                file_name,
+            },
+        )
+    }
+}
+
+#[derive(SqlInsert, SqlSelect, Debug, Default)]
+#[table("HlpbAimConstraint")]
+pub struct HlpbAimConstraintRecord {
+    #[primary_key]
+    pub id: i64,
+    #[references("Hlpb")]
+    pub hlpb_id: i64,
+    pub name: String,
+    pub aim_bone_name1: String,
+    pub aim_bone_name2: String,
+    pub aim_type1: String,
+    pub aim_type2: String,
+    pub target_bone_name1: String,
+    pub target_bone_name2: String,
+    pub unk1: i32,
+    pub unk2: i32,
+    pub unk3: f64,
+    pub unk4: f64,
+    pub unk5: f64,
+    pub unk6: f64,
+    pub unk7: f64,
+    pub unk8: f64,
+    pub unk9: f64,
+    pub unk10: f64,
+    pub unk11: f64,
+    pub unk12: f64,
+    pub unk13: f64,
+    pub unk14: f64,
+    pub unk15: f64,
+    pub unk16: f64,
+    pub unk17: f64,
+    pub unk18: f64,
+    pub unk19: f64,
+    pub unk20: f64,
+    pub unk21: f64,
+    pub unk22: f64,
+}
+
+impl HlpbAimConstraintRecord {
+    pub fn create_record(
+        hlpb_id: i64,
+        constraint: &ssbh_lib::formats::hlpb::HlpbRotateAim,
+    ) -> (i64, HlpbAimConstraintRecord) {
+        let id = LAST_HLPB_AIM_CONSTRAINT_ID.fetch_add(1, Ordering::Relaxed) as i64;
+        (
+            id,
+            HlpbAimConstraintRecord {
+                id,
+                hlpb_id,
+                name: constraint.name.to_string_lossy(),
+                aim_bone_name1: constraint.aim_bone_name1.to_string_lossy(),
+                aim_bone_name2: constraint.aim_bone_name2.to_string_lossy(),
+                aim_type1: constraint.aim_type1.to_string_lossy(),
+                aim_type2: constraint.aim_type2.to_string_lossy(),
+                target_bone_name1: constraint.target_bone_name1.to_string_lossy(),
+                target_bone_name2: constraint.target_bone_name2.to_string_lossy(),
+                unk1: constraint.unk1,
+                unk2: constraint.unk2,
+                unk3: constraint.unk3 as f64,
+                unk4: constraint.unk4 as f64,
+                unk5: constraint.unk5 as f64,
+                unk6: constraint.unk6 as f64,
+                unk7: constraint.unk7 as f64,
+                unk8: constraint.unk8 as f64,
+                unk9: constraint.unk9 as f64,
+                unk10: constraint.unk10 as f64,
+                unk11: constraint.unk11 as f64,
+                unk12: constraint.unk12 as f64,
+                unk13: constraint.unk13 as f64,
+                unk14: constraint.unk14 as f64,
+                unk15: constraint.unk15 as f64,
+                unk16: constraint.unk16 as f64,
+                unk17: constraint.unk17 as f64,
+                unk18: constraint.unk18 as f64,
+                unk19: constraint.unk19 as f64,
+                unk20: constraint.unk20 as f64,
+                unk21: constraint.unk21 as f64,
+                unk22: constraint.unk22 as f64,
+            },
+        )
+    }
+}
+
+#[derive(SqlInsert, SqlSelect, Debug, Default)]
+#[table("HlpbOrientConstraint")]
+pub struct HlpbOrientConstraintRecord {
+    #[primary_key]
+    pub id: i64,
+    #[references("Hlpb")]
+    pub hlpb_id: i64,
+    pub name: String,
+    pub bone_name: String,
+    pub root_bone_name: String,
+    pub parent_bone_name: String,
+    pub driver_bone_name: String,
+    pub unk_type: u32,
+    pub aoi_x: f64,
+    pub aoi_y: f64,
+    pub aoi_z: f64,
+    pub quat1_x: f64,
+    pub quat1_y: f64,
+    pub quat1_z: f64,
+    pub quat1_w: f64,
+    pub quat2_x: f64,
+    pub quat2_y: f64,
+    pub quat2_z: f64,
+    pub quat2_w: f64,
+    pub range_min_x: f64,
+    pub range_min_y: f64,
+    pub range_min_z: f64,
+    pub range_max_x: f64,
+    pub range_max_y: f64,
+    pub range_max_z: f64,
+}
+
+impl HlpbOrientConstraintRecord {
+    pub fn create_record(
+        hlpb_id: i64,
+        constraint: &ssbh_lib::formats::hlpb::HlpbRotateInterpolation,
+    ) -> (i64, HlpbOrientConstraintRecord) {
+        let id = LAST_HLPB_ORIENT_CONSTRAINT_ID.fetch_add(1, Ordering::Relaxed) as i64;
+        (
+            id,
+            HlpbOrientConstraintRecord {
+                id,
+                hlpb_id,
+                name: constraint.name.to_string_lossy(),
+                bone_name: constraint.bone_name.to_string_lossy(),
+                root_bone_name: constraint.root_bone_name.to_string_lossy(),
+                parent_bone_name: constraint.parent_bone_name.to_string_lossy(),
+                driver_bone_name: constraint.driver_bone_name.to_string_lossy(),
+                unk_type: constraint.unk_type,
+                aoi_x: constraint.aoi.x as f64,
+                aoi_y: constraint.aoi.y as f64,
+                aoi_z: constraint.aoi.z as f64,
+                quat1_x: constraint.quat1.x as f64,
+                quat1_y: constraint.quat1.y as f64,
+                quat1_z: constraint.quat1.z as f64,
+                quat1_w: constraint.quat1.w as f64,
+                quat2_x: constraint.quat2.x as f64,
+                quat2_y: constraint.quat2.y as f64,
+                quat2_z: constraint.quat2.z as f64,
+                quat2_w: constraint.quat2.w as f64,
+                range_min_x: constraint.range_min.x as f64,
+                range_min_y: constraint.range_min.y as f64,
+                range_min_z: constraint.range_min.z as f64,
+                range_max_x: constraint.range_max.x as f64,
+                range_max_y: constraint.range_max.y as f64,
+                range_max_z: constraint.range_max.z as f64,
+            },
+        )
+    }
+}
+
+#[derive(SqlInsert, SqlSelect, Debug, Default)]
+#[table("Nufx")]
+pub struct NufxRecord {
+    #[primary_key]
+    pub id: i64,
+    pub directory: String,
+    pub file_name: String,
+}
+
+impl NufxRecord {
+    pub fn create_record(directory: String, file_name: String) -> (i64, NufxRecord) {
+        let id = LAST_NUFX_ID.fetch_add(1, Ordering::Relaxed) as i64;
+        (
+            id,
+            NufxRecord {
+                id,
+                directory,
+                file_name,
+            },
+        )
+    }
+}
+
+#[derive(SqlInsert, SqlSelect, Debug, Default)]
+#[table("ShaderProgram")]
+pub struct ShaderProgramRecord {
+    #[primary_key]
+    pub id: i64,
+    #[references("Nufx")]
+    pub nufx_id: i64,
+    #[index]
+    pub name: String,
+    pub render_pass: String,
+    pub vertex_shader: String,
+    pub unk_shader1: String,
+    pub unk_shader2: String,
+    pub geometry_shader: String,
+    pub pixel_shader: String,
+    pub compute_shader: String,
+}
+
+impl ShaderProgramRecord {
+    pub fn create_record(
+        nufx_id: i64,
+        name: String,
+        render_pass: String,
+        shaders: &ssbh_lib::formats::nufx::ShaderStages,
+    ) -> (i64, ShaderProgramRecord) {
+        let id = LAST_SHADER_PROGRAM_ID.fetch_add(1, Ordering::Relaxed) as i64;
+        (
+            id,
+            ShaderProgramRecord {
+                id,
+                nufx_id,
+                name,
+                render_pass,
+                vertex_shader: shaders.vertex_shader.to_string_lossy(),
+                unk_shader1: shaders.unk_shader1.to_string_lossy(),
+                unk_shader2: shaders.unk_shader2.to_string_lossy(),
+                geometry_shader: shaders.geometry_shader.to_string_lossy(),
+                pixel_shader: shaders.pixel_shader.to_string_lossy(),
+                compute_shader: shaders.compute_shader.to_string_lossy(),
+            },
+        )
+    }
+}
+
+#[derive(SqlInsert, SqlSelect, Debug, Default)]
+#[table("ShaderProgramVertexAttribute")]
+pub struct ShaderProgramVertexAttributeRecord {
+    #[primary_key]
+    pub id: i64,
+    #[references("ShaderProgram")]
+    pub shader_program_id: i64,
+    pub name: String,
+    pub attribute_name: String,
+}
+
+impl ShaderProgramVertexAttributeRecord {
+    pub fn create_record(
+        shader_program_id: i64,
+        name: String,
+        attribute_name: String,
+    ) -> (i64, ShaderProgramVertexAttributeRecord) {
+        let id = LAST_SHADER_PROGRAM_VERTEX_ATTRIBUTE_ID.fetch_add(1, Ordering::Relaxed) as i64;
+        (
+            id,
+            ShaderProgramVertexAttributeRecord {
+                id,
+                shader_program_id,
+                name,
+                attribute_name,
+            },
+        )
+    }
+}
+
+#[derive(SqlInsert, SqlSelect, Debug, Default)]
+#[table("ShaderProgramMaterialParam")]
+pub struct ShaderProgramMaterialParamRecord {
+    #[primary_key]
+    pub id: i64,
+    #[references("ShaderProgram")]
+    pub shader_program_id: i64,
+    #[references("CustomParam")]
+    pub param_id: i64,
+    pub parameter_name: String,
+}
+
+impl ShaderProgramMaterialParamRecord {
+    pub fn create_record(
+        shader_program_id: i64,
+        param_id: i64,
+        parameter_name: String,
+    ) -> (i64, ShaderProgramMaterialParamRecord) {
+        let id = LAST_SHADER_PROGRAM_MATERIAL_PARAM_ID.fetch_add(1, Ordering::Relaxed) as i64;
+        (
+            id,
+            ShaderProgramMaterialParamRecord {
+                id,
+                shader_program_id,
+                param_id,
+                parameter_name,
+            },
+        )
+    }
+}
+
+#[derive(SqlInsert, SqlSelect, Debug, Default)]
+#[table("Adj")]
+pub struct AdjRecord {
+    #[primary_key]
+    pub id: i64,
+    pub directory: String,
+    pub file_name: String,
+    // Linked to the mesh in the same directory after all files are processed.
+    #[references("Mesh")]
+    pub mesh_id: Option<i64>,
+}
+
+impl AdjRecord {
+    pub fn create_record(directory: String, file_name: String) -> (i64, AdjRecord) {
+        let id = LAST_ADJ_ID.fetch_add(1, Ordering::Relaxed) as i64;
+        (
+            id,
+            AdjRecord {
+                id,
+                directory,
+                file_name,
+                mesh_id: None,
+            },
+        )
+    }
+}
+
+#[derive(SqlInsert, SqlSelect, Debug, Default)]
+#[table("AdjEntry")]
+pub struct AdjEntryRecord {
+    #[primary_key]
+    pub id: i64,
+    #[references("Adj")]
+    pub adj_id: i64,
+    pub mesh_object_index: i64,
+    pub index_buffer_offset: i64,
+    pub index_count: i64,
+    #[references("MeshObject")]
+    pub mesh_object_id: Option<i64>,
+}
+
+impl AdjEntryRecord {
+    pub fn create_record(
+        adj_id: i64,
+        mesh_object_index: i64,
+        index_buffer_offset: i64,
+        index_count: i64,
+    ) -> (i64, AdjEntryRecord) {
+        let id = LAST_ADJ_ENTRY_ID.fetch_add(1, Ordering::Relaxed) as i64;
+        (
+            id,
+            AdjEntryRecord {
+                id,
+                adj_id,
+                mesh_object_index,
+                index_buffer_offset,
+                index_count,
+                mesh_object_id: None,
+            },
+        )
+    }
+}
+
+#[derive(SqlInsert, SqlSelect, Debug, Default)]
+#[table("Nrpd")]
+pub struct NrpdRecord {
+    #[primary_key]
+    pub id: i64,
+    pub directory: String,
+    pub file_name: String,
+}
+
+impl NrpdRecord {
+    pub fn create_record(directory: String, file_name: String) -> (i64, NrpdRecord) {
+        let id = LAST_NRPD_ID.fetch_add(1, Ordering::Relaxed) as i64;
+        (
+            id,
+            NrpdRecord {
+                id,
+                directory,
+                file_name,
+            },
+        )
+    }
+}
+
+#[derive(SqlInsert, SqlSelect, Debug, Default)]
+#[table("NrpdFrameBuffer")]
+pub struct NrpdFrameBufferRecord {
+    #[primary_key]
+    pub id: i64,
+    #[references("Nrpd")]
+    pub nrpd_id: i64,
+    pub name: String,
+    pub frame_buffer_type: u32,
+    pub width: Option<u32>,
+    pub height: Option<u32>,
+}
+
+impl NrpdFrameBufferRecord {
+    pub fn create_record(
+        nrpd_id: i64,
+        name: String,
+        frame_buffer_type: u32,
+        width: Option<u32>,
+        height: Option<u32>,
+    ) -> (i64, NrpdFrameBufferRecord) {
+        let id = LAST_NRPD_FRAME_BUFFER_ID.fetch_add(1, Ordering::Relaxed) as i64;
+        (
+            id,
+            NrpdFrameBufferRecord {
+                id,
+                nrpd_id,
+                name,
+                frame_buffer_type,
+                width,
+                height,
+            },
+        )
+    }
+}
+
+#[derive(SqlInsert, SqlSelect, Debug, Default)]
+#[table("NrpdStateContainer")]
+pub struct NrpdStateContainerRecord {
+    #[primary_key]
+    pub id: i64,
+    #[references("Nrpd")]
+    pub nrpd_id: i64,
+    pub name: String,
+    pub state_type: u32,
+}
+
+impl NrpdStateContainerRecord {
+    pub fn create_record(
+        nrpd_id: i64,
+        name: String,
+        state_type: u32,
+    ) -> (i64, NrpdStateContainerRecord) {
+        let id = LAST_NRPD_STATE_CONTAINER_ID.fetch_add(1, Ordering::Relaxed) as i64;
+        (
+            id,
+            NrpdStateContainerRecord {
+                id,
+                nrpd_id,
+                name,
+                state_type,
+            },
+        )
+    }
+}
+
+#[derive(SqlInsert, SqlSelect, Debug, Default)]
+#[table("NrpdSampler")]
+pub struct NrpdSamplerRecord {
+    #[primary_key]
+    pub id: i64,
+    #[references("NrpdStateContainer")]
+    pub state_container_id: i64,
+    pub wraps: u32,
+    pub wrapt: u32,
+    pub wrapr: u32,
+    pub min_filter: u32,
+    pub mag_filter: u32,
+    pub texture_filtering_type: u32,
+    pub border_color_r: f64,
+    pub border_color_g: f64,
+    pub border_color_b: f64,
+    pub border_color_a: f64,
+    pub unk11: u32,
+    pub unk12: u32,
+    pub lod_bias: f64,
+    pub max_anisotropy: u32,
+    pub unk13: i64,
+}
+
+impl NrpdSamplerRecord {
+    pub fn create_record(
+        state_container_id: i64,
+        val: &ssbh_lib::formats::nrpd::NrpdSampler,
+    ) -> (i64, NrpdSamplerRecord) {
+        let id = LAST_NRPD_SAMPLER_ID.fetch_add(1, Ordering::Relaxed) as i64;
+        (
+            id,
+            NrpdSamplerRecord {
+                id,
+                state_container_id,
+                wraps: val.wraps as u32,
+                wrapt: val.wrapt as u32,
+                wrapr: val.wrapr as u32,
+                min_filter: val.min_filter as u32,
+                mag_filter: val.mag_filter as u32,
+                texture_filtering_type: val.texture_filtering_type as u32,
+                border_color_r: val.border_color.r as f64,
+                border_color_g: val.border_color.g as f64,
+                border_color_b: val.border_color.b as f64,
+                border_color_a: val.border_color.a as f64,
+                unk11: val.unk11,
+                unk12: val.unk12,
+                lod_bias: val.lod_bias as f64,
+                max_anisotropy: val.max_anisotropy,
+                unk13: val.unk13 as i64,
+            },
+        )
+    }
+}
+
+#[derive(SqlInsert, SqlSelect, Debug, Default)]
+#[table("NrpdDepthState")]
+pub struct NrpdDepthStateRecord {
+    #[primary_key]
+    pub id: i64,
+    #[references("NrpdStateContainer")]
+    pub state_container_id: i64,
+    pub unk2: u32,
+    pub unk3: u32,
+    pub unk4: u32,
+    pub unk5: u32,
+    pub unk6: u32,
+    pub unk7: u32,
+    pub unk8: i64,
+    pub unk9: i64,
+    pub unk10: i64,
+    pub unk11: i64,
+}
+
+impl NrpdDepthStateRecord {
+    pub fn create_record(
+        state_container_id: i64,
+        val: &ssbh_lib::formats::nrpd::NrpdDepthState,
+    ) -> (i64, NrpdDepthStateRecord) {
+        let id = LAST_NRPD_DEPTH_STATE_ID.fetch_add(1, Ordering::Relaxed) as i64;
+        (
+            id,
+            NrpdDepthStateRecord {
+                id,
+                state_container_id,
+                unk2: val.unk2,
+                unk3: val.unk3,
+                unk4: val.unk4,
+                unk5: val.unk5,
+                unk6: val.unk6,
+                unk7: val.unk7,
+                unk8: val.unk8 as i64,
+                unk9: val.unk9 as i64,
+                unk10: val.unk10 as i64,
+                unk11: val.unk11 as i64,
+            },
+        )
+    }
+}
+
+#[derive(SqlInsert, SqlSelect, Debug, Default)]
+#[table("NrpdBlendState")]
+pub struct NrpdBlendStateRecord {
+    #[primary_key]
+    pub id: i64,
+    #[references("NrpdStateContainer")]
+    pub state_container_id: i64,
+    pub source_color: u32,
+    pub unk2: u32,
+    pub destination_color: u32,
+    pub unk4: u32,
+    pub unk5: u32,
+    pub unk6: u32,
+    pub unk7: u32,
+    pub unk8: u32,
+    pub unk9: u32,
+    pub unk10: u32,
+}
+
+impl NrpdBlendStateRecord {
+    pub fn create_record(
+        state_container_id: i64,
+        val: &ssbh_lib::formats::nrpd::NrpdBlendState,
+    ) -> (i64, NrpdBlendStateRecord) {
+        let id = LAST_NRPD_BLEND_STATE_ID.fetch_add(1, Ordering::Relaxed) as i64;
+        (
+            id,
+            NrpdBlendStateRecord {
+                id,
+                state_container_id,
+                source_color: val.source_color as u32,
+                unk2: val.unk2,
+                destination_color: val.destination_color as u32,
+                unk4: val.unk4,
+                unk5: val.unk5,
+                unk6: val.unk6,
+                unk7: val.unk7,
+                unk8: val.unk8,
+                unk9: val.unk9,
+                unk10: val.unk10,
+            },
+        )
+    }
+}
+
+#[derive(SqlInsert, SqlSelect, Debug, Default)]
+#[table("NrpdRasterizerState")]
+pub struct NrpdRasterizerStateRecord {
+    #[primary_key]
+    pub id: i64,
+    #[references("NrpdStateContainer")]
+    pub state_container_id: i64,
+    pub fill_mode: u32,
+    pub cull_mode: u32,
+    pub depth_bias: f64,
+    pub unk4: f64,
+    pub unk5: f64,
+    pub unk6: u32,
+}
+
+impl NrpdRasterizerStateRecord {
+    pub fn create_record(
+        state_container_id: i64,
+        val: &ssbh_lib::formats::nrpd::NrpdRasterizerState,
+    ) -> (i64, NrpdRasterizerStateRecord) {
+        let id = LAST_NRPD_RASTERIZER_STATE_ID.fetch_add(1, Ordering::Relaxed) as i64;
+        (
+            id,
+            NrpdRasterizerStateRecord {
+                id,
+                state_container_id,
+                fill_mode: val.fill_mode as u32,
+                cull_mode: val.cull_mode as u32,
+                depth_bias: val.depth_bias as f64,
+                unk4: val.unk4 as f64,
+                unk5: val.unk5 as f64,
+                unk6: val.unk6,
+            },
+        )
+    }
+}
+
+#[derive(SqlInsert, SqlSelect, Debug, Default)]
+#[table("Shdr")]
+pub struct ShdrRecord {
+    #[primary_key]
+    pub id: i64,
+    pub directory: String,
+    pub file_name: String,
+}
+
+impl ShdrRecord {
+    pub fn create_record(directory: String, file_name: String) -> (i64, ShdrRecord) {
+        let id = LAST_SHDR_ID.fetch_add(1, Ordering::Relaxed) as i64;
+        (
+            id,
+            ShdrRecord {
+                id,
+                directory,
+                file_name,
+            },
+        )
+    }
+}
+
+#[derive(SqlInsert, SqlSelect, Debug, Default)]
+#[table("Shader")]
+pub struct ShaderRecord {
+    #[primary_key]
+    pub id: i64,
+    #[references("Shdr")]
+    pub shdr_id: i64,
+    #[index]
+    pub name: String,
+    pub shader_type: u32,
+    pub buffer_size: i64,
+    pub binary_size: i64,
+}
+
+impl ShaderRecord {
+    pub fn create_record(
+        shdr_id: i64,
+        shader: &ssbh_lib::formats::shdr::Shader,
+    ) -> (i64, ShaderRecord) {
+        let id = LAST_SHADER_ID.fetch_add(1, Ordering::Relaxed) as i64;
+        (
+            id,
+            ShaderRecord {
+                id,
+                shdr_id,
+                name: shader.name.to_string_lossy(),
+                shader_type: shader.shader_type as u32,
+                buffer_size: shader.shader_binary.elements.len() as i64,
+                binary_size: shader.binary_size as i64,
             },
         )
     }